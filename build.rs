@@ -0,0 +1,40 @@
+use std::env;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::process::Command;
+
+//shown instead of the window when the compiler was not found, so a stale window is never shipped
+const MISSING: &str = r#"var Elm = { Main: { init: function (options) {
+    options.node.innerHTML = "<h1>The window was built without the Elm compiler.</h1>"
+        + "<p>Install Elm 0.19.1 (or set ELM to its path) and build again.</p>";
+    return { ports: { toRust: { subscribe: function () {} }, fromRust: { send: function () {} } } };
+} } };
+"#;
+
+//compiles the window in elm-code into elm.js in OUT_DIR with `elm make`, the compiler is `elm`
+//or the one given in ELM
+fn main() {
+    println!("cargo:rerun-if-changed=elm-code/src");
+    println!("cargo:rerun-if-changed=elm-code/elm.json");
+    println!("cargo:rerun-if-env-changed=ELM");
+    let output = PathBuf::from(env::var("OUT_DIR").unwrap()).join("elm.js");
+    let elm = env::var("ELM").unwrap_or_else(|_| "elm".to_string());
+    match Command::new(&elm)
+        .current_dir("elm-code")
+        .args(&["make", "src/Main.elm", "--output"])
+        .arg(&output)
+        .status()
+    {
+        Ok(status) if status.success() => {}
+        Ok(status) => panic!("elm make failed with {}", status),
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            println!(
+                "cargo:warning={} was not found, the window only tells how to build it",
+                elm
+            );
+            fs::write(&output, MISSING).unwrap();
+        }
+        Err(e) => panic!("cannot run {}: {}", elm, e),
+    }
+}
@@ -1,5 +1,6 @@
-module Date exposing (Date, encode, fromString, toString)
+module Date exposing (Date, decode, encode, fromString, toString)
 
+import Json.Decode as Decode
 import Json.Encode as Encode
 
 
@@ -23,6 +24,14 @@ encode date =
         ]
 
 
+decode : Decode.Decoder Date
+decode =
+    Decode.map3 Date
+        (Decode.field "year" Decode.int)
+        (Decode.field "month" Decode.int)
+        (Decode.field "day" Decode.int)
+
+
 toString : Date -> String
 toString date =
     String.fromInt date.day ++ String.fromInt (date.month * 100) ++ String.fromInt (date.year * 10000)
@@ -1,9 +1,10 @@
 module Device exposing (Device, decode, encode)
 
 import DecodeHelper
+import Dict exposing (Dict)
 import EncodeHelper
 import Json.Decode as Decode
-import Json.Decode.Pipeline exposing (required)
+import Json.Decode.Pipeline exposing (optional, required)
 import Json.Encode as Encode
 import Wafer exposing (Wafer)
 
@@ -14,6 +15,7 @@ type alias Device =
     , temperature : Maybe Int
     , width : Maybe Float
     , length : Maybe Float
+    , extra : Dict String String
     }
 
 
@@ -25,6 +27,7 @@ encode device =
         , ( "temperature", EncodeHelper.maybe Encode.int device.temperature )
         , ( "width", EncodeHelper.maybe Encode.float device.width )
         , ( "length", EncodeHelper.maybe Encode.float device.length )
+        , ( "extra", Encode.dict identity Encode.string device.extra )
         ]
 
 
@@ -36,3 +39,4 @@ decode =
         |> required "temperature" (Decode.nullable Decode.int)
         |> required "width" (Decode.nullable Decode.float)
         |> required "length" (Decode.nullable Decode.float)
+        |> optional "extra" (Decode.dict Decode.string) Dict.empty
@@ -1,8 +1,8 @@
-module FilterOptions exposing (FilterOptions, decode, empty, encode)
+module FilterOptions exposing (Bin, FilterOptions, decode, empty, encode)
 
 import Dict exposing (Dict)
 import Json.Decode as Decode
-import Json.Decode.Pipeline exposing (required)
+import Json.Decode.Pipeline exposing (optional, required)
 import Json.Encode as Encode
 
 
@@ -16,6 +16,16 @@ type alias FilterOptions =
     , test_types : Dict String Int
     , measurement_speeds : Dict String Int
     , dates : Dict String Int
+    , bins : Dict String (List Bin)
+    , terminals : Dict String (Dict String (Dict String Int))
+    }
+
+
+type alias Bin =
+    { label : String
+    , min : Float
+    , max : Float
+    , runs : Int
     }
 
 
@@ -31,6 +41,18 @@ encode query =
         , ( "test_types", Encode.dict identity Encode.int query.test_types )
         , ( "measurement_speeds", Encode.dict identity Encode.int query.test_types )
         , ( "dates", Encode.dict identity Encode.int query.dates )
+        , ( "bins", Encode.dict identity (Encode.list encodeBin) query.bins )
+        , ( "terminals", Encode.dict identity (Encode.dict identity (Encode.dict identity Encode.int)) query.terminals )
+        ]
+
+
+encodeBin : Bin -> Encode.Value
+encodeBin bin =
+    Encode.object
+        [ ( "label", Encode.string bin.label )
+        , ( "min", Encode.float bin.min )
+        , ( "max", Encode.float bin.max )
+        , ( "runs", Encode.int bin.runs )
         ]
 
 
@@ -46,8 +68,19 @@ decode =
         |> required "test_types" (Decode.dict Decode.int)
         |> required "measurement_speeds" (Decode.dict Decode.int)
         |> required "dates" (Decode.dict Decode.int)
+        |> optional "bins" (Decode.dict (Decode.list decodeBin)) Dict.empty
+        |> optional "terminals" (Decode.dict (Decode.dict (Decode.dict Decode.int))) Dict.empty
+
+
+decodeBin : Decode.Decoder Bin
+decodeBin =
+    Decode.succeed Bin
+        |> required "label" Decode.string
+        |> required "min" Decode.float
+        |> required "max" Decode.float
+        |> required "runs" Decode.int
 
 
 empty : FilterOptions
 empty =
-    FilterOptions Dict.empty Dict.empty Dict.empty Dict.empty Dict.empty Dict.empty Dict.empty Dict.empty Dict.empty
+    FilterOptions Dict.empty Dict.empty Dict.empty Dict.empty Dict.empty Dict.empty Dict.empty Dict.empty Dict.empty Dict.empty Dict.empty
@@ -1,7 +1,9 @@
-module FilterQuery exposing (FilterQuery, empty, encode)
+module FilterQuery exposing (FilterQuery, RangeFilter, SettingFilter, TerminalFilter, decode, empty, encode, hasTerminalValue, parseSettings, parseTerminals, rangeOf, setRange, toggleTerminalValue)
 
 import Date exposing (Date)
 import EncodeHelper
+import Json.Decode as Decode
+import Json.Decode.Pipeline exposing (optional, required)
 import Json.Encode as Encode
 import Maybe exposing (Maybe)
 
@@ -16,6 +18,42 @@ type alias FilterQuery =
     , test_type : String
     , measurement_speeds : List String
     , dates_between : ( Maybe Date, Maybe Date )
+    , settings : List SettingFilter
+    , ranges : List RangeFilter
+    , terminals : List TerminalFilter
+    }
+
+
+type alias SettingFilter =
+    { label : String
+    , terminal : Maybe String
+    , value : String
+    }
+
+
+
+-- quantity is "Width", "Length", "Ratio", "Area" or "Temperature" in nm, nm² and K
+
+
+type alias RangeFilter =
+    { quantity : String
+    , min : Maybe Float
+    , max : Maybe Float
+    , below_max : Bool
+    }
+
+
+
+-- property is "Mode", "Instrument", "Bias", "Start", "Stop", "Step", "Compliance",
+-- "VoltageRange", "CurrentRange", "Voltage" or "Current" of the terminal
+
+
+type alias TerminalFilter =
+    { terminal : String
+    , property : String
+    , values : List String
+    , min : Maybe Float
+    , max : Maybe Float
     }
 
 
@@ -31,9 +69,220 @@ encode query =
         , ( "test_type", Encode.string query.test_type )
         , ( "measurement_speeds", Encode.list Encode.string query.measurement_speeds )
         , ( "dates_between", EncodeHelper.tuple2 (EncodeHelper.maybe Date.encode) (EncodeHelper.maybe Date.encode) query.dates_between )
+        , ( "settings", Encode.list encodeSetting query.settings )
+        , ( "ranges", Encode.list encodeRange query.ranges )
+        , ( "terminals", Encode.list encodeTerminal query.terminals )
+        ]
+
+
+encodeSetting : SettingFilter -> Encode.Value
+encodeSetting setting =
+    Encode.object
+        [ ( "label", Encode.string setting.label )
+        , ( "terminal", EncodeHelper.maybe Encode.string setting.terminal )
+        , ( "value", Encode.string setting.value )
+        ]
+
+
+encodeRange : RangeFilter -> Encode.Value
+encodeRange range =
+    Encode.object
+        [ ( "quantity", Encode.string range.quantity )
+        , ( "min", EncodeHelper.maybe Encode.float range.min )
+        , ( "max", EncodeHelper.maybe Encode.float range.max )
+        , ( "below_max", Encode.bool range.below_max )
+        ]
+
+
+encodeTerminal : TerminalFilter -> Encode.Value
+encodeTerminal filter =
+    Encode.object
+        [ ( "terminal", Encode.string filter.terminal )
+        , ( "property", Encode.string filter.property )
+        , ( "values", Encode.list Encode.string filter.values )
+        , ( "min", EncodeHelper.maybe Encode.float filter.min )
+        , ( "max", EncodeHelper.maybe Encode.float filter.max )
         ]
 
 
+-- the filters of a saved query
+
+
+decode : Decode.Decoder FilterQuery
+decode =
+    let
+        date =
+            Decode.nullable Date.decode
+    in
+    Decode.succeed FilterQuery
+        |> required "sheet_names" (Decode.list Decode.string)
+        |> required "widths" (Decode.list Decode.string)
+        |> required "lengths" (Decode.list Decode.string)
+        |> required "temps" (Decode.list Decode.string)
+        |> required "wafer" Decode.string
+        |> required "dies" (Decode.list Decode.string)
+        |> required "test_type" Decode.string
+        |> required "measurement_speeds" (Decode.list Decode.string)
+        |> required "dates_between" (Decode.map2 Tuple.pair (Decode.index 0 date) (Decode.index 1 date))
+        |> optional "settings" (Decode.list decodeSetting) []
+        |> optional "ranges" (Decode.list decodeRange) []
+        |> optional "terminals" (Decode.list decodeTerminal) []
+
+
+decodeSetting : Decode.Decoder SettingFilter
+decodeSetting =
+    Decode.succeed SettingFilter
+        |> required "label" Decode.string
+        |> optional "terminal" (Decode.nullable Decode.string) Nothing
+        |> required "value" Decode.string
+
+
+decodeRange : Decode.Decoder RangeFilter
+decodeRange =
+    Decode.succeed RangeFilter
+        |> required "quantity" Decode.string
+        |> optional "min" (Decode.nullable Decode.float) Nothing
+        |> optional "max" (Decode.nullable Decode.float) Nothing
+        |> optional "below_max" Decode.bool False
+
+
+decodeTerminal : Decode.Decoder TerminalFilter
+decodeTerminal =
+    Decode.succeed TerminalFilter
+        |> required "terminal" Decode.string
+        |> required "property" Decode.string
+        |> optional "values" (Decode.list Decode.string) []
+        |> optional "min" (Decode.nullable Decode.float) Nothing
+        |> optional "max" (Decode.nullable Decode.float) Nothing
+
+
+rangeOf : String -> FilterQuery -> Maybe RangeFilter
+rangeOf quantity query =
+    query.ranges
+        |> List.filter (\range -> range.quantity == quantity)
+        |> List.head
+
+
+
+-- without min and max the range of the quantity is removed
+
+
+setRange : RangeFilter -> FilterQuery -> FilterQuery
+setRange range query =
+    let
+        others =
+            List.filter (\other -> other.quantity /= range.quantity) query.ranges
+    in
+    if range.min == Nothing && range.max == Nothing then
+        { query | ranges = others }
+
+    else
+        { query | ranges = others ++ [ range ] }
+
+
+
+-- "Sweep Delay=1E-3; Drain:Compliance=0.1"
+
+
+parseSettings : String -> List SettingFilter
+parseSettings input =
+    let
+        parseOne : String -> Maybe SettingFilter
+        parseOne part =
+            case String.split "=" part of
+                [ key, val ] ->
+                    case String.split ":" key of
+                        [ terminal, label ] ->
+                            Just (SettingFilter (String.trim label) (Just (String.trim terminal)) (String.trim val))
+
+                        _ ->
+                            Just (SettingFilter (String.trim key) Nothing (String.trim val))
+
+                _ ->
+                    Nothing
+    in
+    input
+        |> String.split ";"
+        |> List.filter (\part -> String.trim part /= "")
+        |> List.filterMap parseOne
+
+
+-- "Drain:Bias=0.05; Gate:Bias=0.3..0.6; Gate:Instrument=SMU2,SMU3"
+
+
+parseTerminals : String -> List TerminalFilter
+parseTerminals input =
+    let
+        known : List String -> String -> Maybe String
+        known names name =
+            names
+                |> List.filter (\n -> String.toLower n == String.toLower (String.trim name))
+                |> List.head
+
+        terminal =
+            known [ "Gate", "Drain", "Source", "Bulk" ]
+
+        property =
+            known [ "Mode", "Instrument", "Bias", "Start", "Stop", "Step", "Compliance", "VoltageRange", "CurrentRange", "Voltage", "Current" ]
+
+        parseOne : String -> Maybe TerminalFilter
+        parseOne part =
+            case String.split "=" part of
+                [ key, val ] ->
+                    case String.split ":" key of
+                        [ t, p ] ->
+                            case String.split ".." val of
+                                [ low, high ] ->
+                                    Maybe.map2 (\tt pp -> TerminalFilter tt pp [] (String.toFloat (String.trim low)) (String.toFloat (String.trim high))) (terminal t) (property p)
+
+                                _ ->
+                                    Maybe.map2 (\tt pp -> TerminalFilter tt pp (List.map String.trim (String.split "," val)) Nothing Nothing) (terminal t) (property p)
+
+                        _ ->
+                            Nothing
+
+                _ ->
+                    Nothing
+    in
+    input
+        |> String.split ";"
+        |> List.filter (\part -> String.trim part /= "")
+        |> List.filterMap parseOne
+
+
+hasTerminalValue : String -> String -> String -> FilterQuery -> Bool
+hasTerminalValue terminal property value query =
+    List.any (\filter -> filter.terminal == terminal && filter.property == property && List.member value filter.values) query.terminals
+
+
+toggleTerminalValue : String -> String -> String -> FilterQuery -> FilterQuery
+toggleTerminalValue terminal property value query =
+    let
+        same filter =
+            filter.terminal == terminal && filter.property == property
+
+        others =
+            List.filter (not << same) query.terminals
+
+        values =
+            query.terminals
+                |> List.filter same
+                |> List.concatMap .values
+
+        toggled =
+            if List.member value values then
+                List.filter ((/=) value) values
+
+            else
+                values ++ [ value ]
+    in
+    if List.isEmpty toggled then
+        { query | terminals = others }
+
+    else
+        { query | terminals = others ++ [ TerminalFilter terminal property toggled Nothing Nothing ] }
+
+
 empty : FilterQuery
 empty =
-    FilterQuery [] [] [] [] "" [] "" [] ( Nothing, Nothing )
+    FilterQuery [] [] [] [] "" [] "" [] ( Nothing, Nothing ) [] [] []
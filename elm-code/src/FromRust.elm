@@ -1,10 +1,14 @@
 module FromRust exposing (FromRust, decode)
 
 import FilterOptions exposing (FilterOptions)
+import IngestReport exposing (FileReport)
 import Json.Decode as Decode
-import Json.Decode.Pipeline exposing (required)
+import Json.Decode.Pipeline exposing (optional, required)
 import MeasurementCompact exposing (MeasurementCompact)
+import QueryError exposing (QueryError)
 import RustTask exposing (RustTask)
+import Saved exposing (Saved)
+import ScanProgress exposing (ScanProgress)
 
 
 type alias FromRust =
@@ -12,6 +16,11 @@ type alias FromRust =
     , task_done : RustTask
     , measurements : List MeasurementCompact
     , filter_options : FilterOptions
+    , ingest_report : List FileReport
+    , scan : ScanProgress
+    , query_error : Maybe QueryError
+    , saved : Saved
+    , saved_error : Maybe String
     }
 
 
@@ -22,3 +31,8 @@ decode =
         |> required "task_done" RustTask.decode
         |> required "measurements" (Decode.list MeasurementCompact.decode)
         |> required "filter_options" FilterOptions.decode
+        |> optional "ingest_report" (Decode.list IngestReport.decode) []
+        |> optional "scan" ScanProgress.decode ScanProgress.empty
+        |> optional "query_error" (Decode.nullable QueryError.decode) Nothing
+        |> optional "saved" Saved.decode Saved.empty
+        |> optional "saved_error" (Decode.nullable Decode.string) Nothing
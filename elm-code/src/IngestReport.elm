@@ -0,0 +1,153 @@
+module IngestReport exposing (FileReport, FileStatus(..), decode, isFailed, statusToString, summary)
+
+import Json.Decode as Decode
+import Json.Decode.Pipeline exposing (optional, required)
+import Json.Encode as Encode
+
+
+type FileStatus
+    = Imported Int
+    | Updated Int Int Int
+    | Skipped String
+    | Failed String
+    | Vanished Int Bool
+
+
+type alias FileReport =
+    { file : String
+    , status : FileStatus
+    }
+
+
+decode : Decode.Decoder FileReport
+decode =
+    Decode.succeed FileReport
+        |> required "file" Decode.string
+        |> required "status" decodeStatus
+
+
+decodeStatus : Decode.Decoder FileStatus
+decodeStatus =
+    Decode.field "status" Decode.string
+        |> Decode.andThen
+            (\str ->
+                case str of
+                    "Ok" ->
+                        Decode.map Imported (Decode.field "measurements" Decode.int)
+
+                    "Updated" ->
+                        Decode.map3 Updated
+                            (Decode.field "added" Decode.int)
+                            (Decode.field "updated" Decode.int)
+                            (Decode.field "removed" Decode.int)
+
+                    "Vanished" ->
+                        Decode.map2 Vanished
+                            (Decode.field "measurements" Decode.int)
+                            (Decode.field "purged" Decode.bool)
+
+                    "Skipped" ->
+                        Decode.map Skipped (Decode.field "reason" Decode.string)
+
+                    "Failed" ->
+                        Decode.map Failed (Decode.field "error" decodeError)
+
+                    _ ->
+                        Decode.fail "Error parsing FileStatus"
+            )
+
+
+decodeError : Decode.Decoder String
+decodeError =
+    Decode.succeed errorToString
+        |> optional "sheet" (Decode.nullable Decode.string) Nothing
+        |> optional "cell" (Decode.nullable (Decode.map2 Tuple.pair (Decode.index 0 Decode.int) (Decode.index 1 Decode.int))) Nothing
+        |> required "kind" (Decode.map (Encode.encode 0) Decode.value)
+
+
+errorToString : Maybe String -> Maybe ( Int, Int ) -> String -> String
+errorToString sheet cell kind =
+    let
+        location =
+            case ( sheet, cell ) of
+                ( Just s, Just ( row, column ) ) ->
+                    s ++ " row " ++ String.fromInt (row + 1) ++ " column " ++ String.fromInt (column + 1) ++ ": "
+
+                ( Just s, Nothing ) ->
+                    s ++ ": "
+
+                _ ->
+                    ""
+    in
+    location ++ kind
+
+
+isFailed : FileReport -> Bool
+isFailed report =
+    case report.status of
+        Failed _ ->
+            True
+
+        _ ->
+            False
+
+
+statusToString : FileStatus -> String
+statusToString status =
+    case status of
+        Imported amount ->
+            "ok (" ++ String.fromInt amount ++ " measurements)"
+
+        Updated added updated removed ->
+            "modified: "
+                ++ String.fromInt added
+                ++ " added, "
+                ++ String.fromInt updated
+                ++ " updated, "
+                ++ String.fromInt removed
+                ++ " removed"
+
+        Vanished amount purged ->
+            if purged then
+                "vanished: " ++ String.fromInt amount ++ " measurements removed"
+
+            else
+                "vanished: " ++ String.fromInt amount ++ " measurements kept"
+
+        Skipped reason ->
+            "skipped: " ++ reason
+
+        Failed reason ->
+            "failed: " ++ reason
+
+
+changes : FileStatus -> ( Int, Int, Int )
+changes status =
+    case status of
+        Imported amount ->
+            ( amount, 0, 0 )
+
+        Updated added updated removed ->
+            ( added, updated, removed )
+
+        Vanished amount True ->
+            ( 0, 0, amount )
+
+        _ ->
+            ( 0, 0, 0 )
+
+
+summary : List FileReport -> String
+summary reports =
+    let
+        ( added, updated, removed ) =
+            reports
+                |> List.map (.status >> changes)
+                |> List.foldl (\( a, u, r ) ( sa, su, sr ) -> ( sa + a, su + u, sr + r )) ( 0, 0, 0 )
+    in
+    String.fromInt added
+        ++ " measurements added, "
+        ++ String.fromInt updated
+        ++ " updated, "
+        ++ String.fromInt removed
+        ++ " removed"
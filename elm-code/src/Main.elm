@@ -8,10 +8,11 @@ import FilterOptions exposing (FilterOptions)
 import FilterQuery exposing (..)
 import FromRust exposing (..)
 import Html exposing (Html, br, button, div, form, h1, hr, input, li, option, select, span, table, td, text, th, tr, ul)
-import Html.Attributes exposing (autofocus, checked, class, classList, colspan, disabled, id, multiple, selected, size, type_, value)
+import Html.Attributes exposing (autofocus, checked, class, classList, colspan, disabled, id, multiple, placeholder, selected, size, type_, value)
 import Html.Events exposing (onCheck, onClick, onInput, onSubmit)
 import Html.Keyed exposing (..)
 import Html.Lazy exposing (lazy)
+import IngestReport exposing (FileReport)
 import Instrument
 import Json.Decode as Decode
 import Json.Decode.Pipeline exposing (..)
@@ -24,7 +25,11 @@ import MultiSelect exposing (Item, Options, multiSelect)
 import OpModeType
 import ProcessQuery exposing (ProcessQuery)
 import ProcessingType exposing (ProcessingType)
+import QueryError exposing (QueryError)
 import RustTask exposing (..)
+import Saved exposing (Saved, SavedQuery(..))
+import ScanProgress exposing (ScanProgress)
+import Series
 import Terminal
 import TestData exposing (TestData)
 import TestDataCompact exposing (TestDataCompact)
@@ -62,6 +67,7 @@ type alias M_ID =
 type Pages
     = SelectPage
     | ProcessPage
+    | ReportPage
 
 
 type alias Entry =
@@ -80,6 +86,13 @@ type alias Model =
     , combine_data : Bool
     , normalize_data : Bool
     , process_options_selected : List ProcessingType
+    , ingest_report : List FileReport
+    , scan : ScanProgress
+    , query_text : String
+    , query_error : Maybe QueryError
+    , saved : Saved
+    , saved_error : Maybe String
+    , saved_name : String
     }
 
 
@@ -93,15 +106,27 @@ type Msg
     | ChangeFilter FilterQuery
     | ChangeWafer String
     | ChangeTestType String
+    | ChangeQuery String
+    | RunQuery
+    | ChangeSavedName String
+    | SaveFilters
+    | SaveText
+    | SaveSelection
+    | OpenSaved SavedQuery
+    | OpenCollection String
+    | ProcessCollection String
+    | RenameSaved String String
+    | DeleteSaved String String
     | UpdateModel FromRust
     | ToSelectPage
     | ToProcessPage
+    | ToReportPage
     | ProcessData
 
 
 init : () -> ( Model, Cmd Msg )
 init _ =
-    ( { message_nr = 0, page = SelectPage, selected_entries = Dict.empty, measurements = [], filters_used = FilterQuery.empty, filter_options = FilterOptions.empty, combine_data = False, normalize_data = False, process_options_selected = [] }, toRust (Encode.object [ ( "torust", Encode.string "Init" ) ]) )
+    ( { message_nr = 0, page = SelectPage, selected_entries = Dict.empty, measurements = [], filters_used = FilterQuery.empty, filter_options = FilterOptions.empty, combine_data = False, normalize_data = False, process_options_selected = [], ingest_report = [], scan = ScanProgress.empty, query_text = "", query_error = Nothing, saved = Saved.empty, saved_error = Nothing, saved_name = "" }, toRust (Encode.object [ ( "torust", Encode.string "Init" ) ]) )
 
 
 
@@ -120,11 +145,17 @@ update msg model =
             let
                 make_data_list testdata =
                     let
-                        base_data n =
-                            { terminal = testdata.terminal, unit = testdata.unit, count = n }
+                        numbers =
+                            if List.isEmpty testdata.series then
+                                List.range 1 testdata.count
+
+                            else
+                                testdata.series
+
+                        base_data n number =
+                            { terminal = testdata.terminal, unit = testdata.unit, name = testdata.name, count = n, series = [ number ] }
                     in
-                    List.range 1 testdata.count
-                        |> List.map base_data
+                    List.indexedMap (\i number -> base_data (i + 1) number) numbers
 
                 all_data =
                     model
@@ -212,11 +243,17 @@ update msg model =
             let
                 make_data_list testdata =
                     let
-                        base_data n =
-                            { terminal = testdata.terminal, unit = testdata.unit, count = n }
+                        numbers =
+                            if List.isEmpty testdata.series then
+                                List.range 1 testdata.count
+
+                            else
+                                testdata.series
+
+                        base_data n number =
+                            { terminal = testdata.terminal, unit = testdata.unit, name = testdata.name, count = n, series = [ number ] }
                     in
-                    List.range 1 testdata.count
-                        |> List.map base_data
+                    List.indexedMap (\i number -> base_data (i + 1) number) numbers
 
                 all_data id =
                     model
@@ -275,6 +312,54 @@ update msg model =
             in
             ( { model | filters_used = new_filter }, toRust (ToRust.filter new_filter) )
 
+        ChangeQuery text ->
+            ( { model | query_text = text }, Cmd.none )
+
+        RunQuery ->
+            ( model, toRust (ToRust.query model.query_text) )
+
+        ChangeSavedName name ->
+            ( { model | saved_name = name }, Cmd.none )
+
+        SaveFilters ->
+            ( { model | saved_name = "" }, toRust (ToRust.saveQuery model.saved_name (FilterSaved model.filters_used)) )
+
+        SaveText ->
+            ( { model | saved_name = "" }, toRust (ToRust.saveQuery model.saved_name (TextSaved model.query_text)) )
+
+        SaveSelection ->
+            let
+                ids =
+                    model.selected_entries
+                        |> Dict.filter (\_ entry -> entry.selected)
+                        |> Dict.keys
+            in
+            ( { model | saved_name = "" }, toRust (ToRust.saveCollection model.saved_name ids) )
+
+        OpenSaved saved ->
+            case saved of
+                FilterSaved filter ->
+                    ( { model | filters_used = filter }, toRust (ToRust.filter filter) )
+
+                TextSaved text ->
+                    ( { model | query_text = text }, toRust (ToRust.query text) )
+
+        OpenCollection name ->
+            ( model, toRust (ToRust.collection name) )
+
+        ProcessCollection name ->
+            let
+                query =
+                    { what = model.process_options_selected, combined = model.combine_data, from = [], collection = Just name }
+            in
+            ( model, toRust (ToRust.process query) )
+
+        RenameSaved kind name ->
+            ( { model | saved_name = "" }, toRust (ToRust.rename kind name model.saved_name) )
+
+        DeleteSaved kind name ->
+            ( model, toRust (ToRust.delete kind name) )
+
         UpdateModel fromrust ->
             let
                 selected_entries_adjusted =
@@ -310,19 +395,25 @@ update msg model =
                     , widths = Dict.keys fromrust.filter_options.widths
                     , lengths = Dict.keys fromrust.filter_options.lengths
                     , temps = Dict.keys fromrust.filter_options.temps
-                    , wafer = Maybe.withDefault "MINOXG" (List.head (Dict.keys fromrust.filter_options.processes))
+                    , wafer = Maybe.withDefault "" (List.head (Dict.keys fromrust.filter_options.processes))
                     , dies = Dict.keys fromrust.filter_options.dies
                     , test_type = Maybe.withDefault "Sampling" (List.head (Dict.keys fromrust.filter_options.test_types))
                     , measurement_speeds = Dict.keys fromrust.filter_options.measurement_speeds
                     , dates_between = ( Nothing, Nothing )
+                    , settings = model.filters_used.settings
+                    , ranges = model.filters_used.ranges
+                    , terminals = model.filters_used.terminals
                     }
             in
             if model.message_nr /= fromrust.message_nr then
                 if fromrust.task_done == RustTask.init then
-                    ( { model | combine_data = False, normalize_data = False, process_options_selected = [], page = SelectPage, message_nr = fromrust.message_nr, measurements = fromrust.measurements, selected_entries = selected_entries_init, filters_used = filters_used_init, filter_options = fromrust.filter_options }, toRust (ToRust.log "Init received") )
+                    ( { model | combine_data = False, normalize_data = False, process_options_selected = [], page = SelectPage, message_nr = fromrust.message_nr, measurements = fromrust.measurements, selected_entries = selected_entries_init, filters_used = filters_used_init, filter_options = fromrust.filter_options, ingest_report = fromrust.ingest_report, scan = fromrust.scan, query_error = fromrust.query_error, saved = fromrust.saved, saved_error = fromrust.saved_error }, toRust (ToRust.log "Init received") )
+
+                else if RustTask.isScanning fromrust.task_done then
+                    ( { model | message_nr = fromrust.message_nr, scan = fromrust.scan }, Cmd.none )
 
                 else
-                    ( { model | message_nr = fromrust.message_nr, measurements = fromrust.measurements, selected_entries = selected_entries_adjusted, filter_options = fromrust.filter_options }, toRust (ToRust.log "Done") )
+                    ( { model | message_nr = fromrust.message_nr, measurements = fromrust.measurements, selected_entries = selected_entries_adjusted, filter_options = fromrust.filter_options, ingest_report = fromrust.ingest_report, scan = fromrust.scan, query_error = fromrust.query_error, saved = fromrust.saved, saved_error = fromrust.saved_error }, toRust (ToRust.log "Done") )
 
             else
                 ( model, Cmd.none )
@@ -333,6 +424,9 @@ update msg model =
         ToProcessPage ->
             ( { model | page = ProcessPage }, toRust (ToRust.log "Process Page Opened") )
 
+        ToReportPage ->
+            ( { model | page = ReportPage }, toRust (ToRust.log "Report Page Opened") )
+
         ProcessData ->
             let
                 processdata =
@@ -343,7 +437,7 @@ update msg model =
                         |> List.map (\( k, e ) -> { id = k, data = e.data })
 
                 query =
-                    { what = model.process_options_selected, combined = model.combine_data, from = processdata }
+                    { what = model.process_options_selected, combined = model.combine_data, from = processdata, collection = Nothing }
             in
             ( model, toRust (ToRust.process query) )
 
@@ -352,6 +446,14 @@ update msg model =
 -- VIEW
 
 
+-- above this many values W, L and T are only offered in bins
+
+
+exactFacets : Int
+exactFacets =
+    12
+
+
 filterview : Model -> List (Html Msg)
 filterview model =
     let
@@ -421,7 +523,10 @@ filterview model =
                 div [ class "filter" ] [ text "nope" ]
 
         sel_widths =
-            if Dict.size opts.widths > 1 then
+            if Dict.size opts.widths > exactFacets then
+                div [ class "never" ] []
+
+            else if Dict.size opts.widths > 1 then
                 multiSelect { items = opt_items opts.widths, onChange = \s -> ChangeFilter { filter | widths = s } } [ class "filter", autofocus True, Html.Attributes.size (Dict.size opts.widths) ] filter.widths
 
             else if Dict.size opts.widths == 1 then
@@ -440,7 +545,10 @@ filterview model =
                 div [ class "filter" ] [ text "nope" ]
 
         sel_lengths =
-            if Dict.size opts.lengths > 1 then
+            if Dict.size opts.lengths > exactFacets then
+                div [ class "never" ] []
+
+            else if Dict.size opts.lengths > 1 then
                 multiSelect { items = opt_items opts.lengths, onChange = \s -> ChangeFilter { filter | lengths = s } } [ class "filter", autofocus True, Html.Attributes.size (Dict.size opts.lengths) ] filter.lengths
 
             else if Dict.size opts.lengths == 1 then
@@ -459,7 +567,10 @@ filterview model =
                 div [ class "filter" ] [ text "nope" ]
 
         sel_temps =
-            if Dict.size opts.temps > 1 then
+            if Dict.size opts.temps > exactFacets then
+                div [ class "never" ] []
+
+            else if Dict.size opts.temps > 1 then
                 multiSelect { items = opt_items opts.temps, onChange = \s -> ChangeFilter { filter | temps = s } } [ class "filter", autofocus True, Html.Attributes.size (Dict.size opts.temps) ] filter.temps
 
             else if Dict.size opts.temps == 1 then
@@ -527,6 +638,131 @@ filterview model =
 
             else
                 div [ class "never" ] [ text "nope" ]
+
+        sel_settings =
+            div [ class "filter" ]
+                [ text "Settings:"
+                , br [] []
+                , input [ placeholder "Sweep Delay=1E-3; Drain:Compliance=0.1", onInput (\s -> ChangeFilter { filter | settings = FilterQuery.parseSettings s }) ] []
+                ]
+
+        -- W and L in µm, areas in µm², temperatures in K
+        sel_range ( quantity, title, ( scale, unit ) ) =
+            let
+                bins =
+                    Maybe.withDefault [] (Dict.get quantity opts.bins)
+
+                current =
+                    FilterQuery.rangeOf quantity filter
+
+                bound : String -> Maybe Float
+                bound s =
+                    Maybe.map (\v -> v * scale) (String.toFloat (String.trim s))
+
+                set : Maybe Float -> Maybe Float -> Bool -> Msg
+                set min max below_max =
+                    ChangeFilter (FilterQuery.setRange (RangeFilter quantity min max below_max) filter)
+
+                bin_button : FilterOptions.Bin -> Html Msg
+                bin_button bin =
+                    button
+                        [ classList [ ( "selected_bin", current == Just (RangeFilter quantity (Just bin.min) (Just bin.max) True) ) ]
+                        , onClick (set (Just bin.min) (Just bin.max) True)
+                        ]
+                        [ text (bin.label ++ " (" ++ String.fromInt bin.runs ++ ")") ]
+            in
+            if List.isEmpty bins && current == Nothing then
+                div [ class "never" ] []
+
+            else
+                div [ class "filter" ]
+                    ([ text (title ++ ":"), br [] [] ]
+                        ++ List.map bin_button bins
+                        ++ [ button [ onClick (set Nothing Nothing False) ] [ text "all" ]
+                           , br [] []
+                           , input [ placeholder ("min " ++ unit), onInput (\s -> set (bound s) (Maybe.andThen .max current) False) ] []
+                           , input [ placeholder ("max " ++ unit), onInput (\s -> set (Maybe.andThen .min current) (bound s) False) ] []
+                           ]
+                    )
+
+        sel_terminals =
+            let
+                value_button : String -> String -> ( String, Int ) -> Html Msg
+                value_button terminal property ( value, count ) =
+                    button
+                        [ classList [ ( "selected_bin", FilterQuery.hasTerminalValue terminal property value filter ) ]
+                        , onClick (ChangeFilter (FilterQuery.toggleTerminalValue terminal property value filter))
+                        ]
+                        [ text (value ++ " (" ++ String.fromInt count ++ ")") ]
+
+                property_row : String -> ( String, Dict String Int ) -> Html Msg
+                property_row terminal ( property, values ) =
+                    div [] (text (property ++ ": ") :: List.map (value_button terminal property) (Dict.toList values))
+
+                terminal_rows : ( String, Dict String (Dict String Int) ) -> Html Msg
+                terminal_rows ( terminal, properties ) =
+                    div [] (text terminal :: List.map (property_row terminal) (Dict.toList properties))
+            in
+            if Dict.isEmpty opts.terminals then
+                div [ class "never" ] []
+
+            else
+                div [ class "filter" ]
+                    ([ text "Terminals:"
+                     , br [] []
+                     , input [ placeholder "Drain:Bias=0.05; Gate:Bias=0.3..0.6; Gate:Instrument=SMU2", onInput (\s -> ChangeFilter { filter | terminals = FilterQuery.parseTerminals s }) ] []
+                     ]
+                        ++ List.map terminal_rows (Dict.toList opts.terminals)
+                    )
+
+        sel_query =
+            div [ class "filter" ]
+                [ text "Query:"
+                , br [] []
+                , form [ onSubmit RunQuery ]
+                    [ input [ placeholder "wafer in (GF22, MINOXG) and W >= 1um", value model.query_text, onInput ChangeQuery ] []
+                    , button [ type_ "submit" ] [ text "Run" ]
+                    ]
+                , div [ class "query_error" ] [ text (Maybe.withDefault "" (Maybe.map QueryError.toString model.query_error)) ]
+                ]
+
+        -- the name typed in is used to save the filters, the query or the selected runs, or to rename one of them
+        sel_saved =
+            let
+                free kind =
+                    Saved.isFree kind model.saved_name model.saved
+
+                any_selected =
+                    model.selected_entries
+                        |> Dict.values
+                        |> List.any .selected
+
+                entry : String -> Msg -> String -> String -> Html Msg
+                entry kind open name label =
+                    div []
+                        [ button [ onClick open ] [ text label ]
+                        , button [ onClick (RenameSaved kind name), disabled (not (free kind)) ] [ text "rename" ]
+                        , button [ onClick (DeleteSaved kind name) ] [ text "delete" ]
+                        ]
+
+                query_entry ( name, saved ) =
+                    entry "Query" (OpenSaved saved) name name
+
+                collection_entry ( name, ids ) =
+                    entry "Collection" (OpenCollection name) name (name ++ " (" ++ String.fromInt (List.length ids) ++ " runs)")
+            in
+            div [ class "filter" ]
+                ([ text "Saved:"
+                 , br [] []
+                 , input [ placeholder "name", value model.saved_name, onInput ChangeSavedName ] []
+                 , button [ onClick SaveFilters, disabled (not (free "Query")) ] [ text "save filters" ]
+                 , button [ onClick SaveText, disabled (not (free "Query") || String.isEmpty (String.trim model.query_text)) ] [ text "save query" ]
+                 , button [ onClick SaveSelection, disabled (not (free "Collection") || not any_selected) ] [ text "save selected runs" ]
+                 , div [ class "query_error" ] [ text (Maybe.withDefault "" model.saved_error) ]
+                 ]
+                    ++ List.map query_entry (Dict.toList model.saved.queries)
+                    ++ List.map collection_entry (Dict.toList model.saved.collections)
+                )
     in
     [ sel_process
     , sel_dies
@@ -535,7 +771,18 @@ filterview model =
     , sel_lengths
     , sel_test_types
     , sel_measurement_speeds
+    , sel_settings
+    , sel_terminals
+    , sel_query
+    , sel_saved
     ]
+        ++ List.map sel_range
+            [ ( "Width", "W", ( 1000, "µm" ) )
+            , ( "Length", "L", ( 1000, "µm" ) )
+            , ( "Ratio", "W/L", ( 1, "" ) )
+            , ( "Area", "Area", ( 1000000, "µm²" ) )
+            , ( "Temperature", "T", ( 1, "K" ) )
+            ]
 
 
 measurementview : Model -> List (Html Msg)
@@ -750,6 +997,7 @@ processoptionsview model =
                 ++ [ normalize ]
                 ++ List.concatMap opt possible_process_options
                 ++ [ button [ onClick ProcessData ] [ text "Process" ] ]
+                ++ List.map (\name -> button [ onClick (ProcessCollection name) ] [ text ("Process " ++ name) ]) (Dict.keys model.saved.collections)
     in
     [ div [ id "param_used" ]
         [ h1 [] [ text "Parameters used:" ]
@@ -838,6 +1086,11 @@ testdataselectionview model =
                         Nothing ->
                             []
 
+                extra =
+                    measurement.device.extra
+                        |> Dict.toList
+                        |> List.map (\( k, v ) -> tr [] [ td [] [ text k ], td [] [ text v ] ])
+
                 testtype =
                     let
                         string =
@@ -1210,7 +1463,7 @@ testdataselectionview model =
                             List.map inner testdata_without_time
 
                         colgroup i =
-                            th [ class "text_table", colspan (List.length testdata_without_time) ] [ input [ type_ "checkbox" ] [], text ("Series " ++ String.fromInt i) ]
+                            th [ class "text_table", colspan (List.length testdata_without_time) ] [ input [ type_ "checkbox" ] [], text (Series.label measurement.series i) ]
                     in
                     if measurement.test_parameter.test_type == TestType.sampling then
                         [ tr [] (th [ class "text_table" ] [ text "" ] :: List.map colgroup colgroup_range)
@@ -1228,6 +1481,8 @@ testdataselectionview model =
                         [ text "ID"
                         , br [] []
                         , h1 [] [ text measurement.id ]
+                        , text measurement.alias
+                        , br [] []
                         , text "Timestamp"
                         , br [] []
                         , text (TimeStamp.toString measurement.test_time_stamp)
@@ -1239,6 +1494,7 @@ testdataselectionview model =
                             ++ temp
                             ++ width
                             ++ length
+                            ++ extra
                         )
                     , table
                         [ class "testdata_measurement" ]
@@ -1262,6 +1518,36 @@ testdataselectionview model =
     List.map singleview selected_measurements
 
 
+reportview : Model -> List (Html Msg)
+reportview model =
+    let
+        row : FileReport -> Html Msg
+        row report =
+            tr []
+                [ td [ class "text_table" ] [ text report.file ]
+                , td [ class "text_table" ] [ text (IngestReport.statusToString report.status) ]
+                ]
+    in
+    [ tr [] [ th [ colspan 2 ] [ text (IngestReport.summary model.ingest_report) ] ]
+    , tr []
+        [ th [] [ text "File" ]
+        , th [] [ text "Status" ]
+        ]
+    ]
+        ++ (model
+                |> .ingest_report
+                |> List.sortBy
+                    (\r ->
+                        if IngestReport.isFailed r then
+                            0
+
+                        else
+                            1
+                    )
+                |> List.map (lazy row)
+           )
+
+
 view : Model -> Html Msg
 view model =
     let
@@ -1287,6 +1573,14 @@ view model =
                     [ div [ id "process_options_container" ] (processoptionsview model)
                     , div [ id "selected_testdata_list" ] (testdataselectionview model)
                     ]
+
+                ReportPage ->
+                    [ table [ id "data_container" ] (reportview model) ]
+
+        amount_failed =
+            model.ingest_report
+                |> List.filter IngestReport.isFailed
+                |> List.length
     in
     div
         [ class "container" ]
@@ -1304,7 +1598,11 @@ view model =
                     , button
                         [ onClick ToProcessPage, disabled (amount_selected == 0) ]
                         [ text "Process Data" ]
+                    , button
+                        [ onClick ToReportPage ]
+                        [ text ("Import Report (" ++ String.fromInt amount_failed ++ " failed)") ]
                     ]
+                , div [ id "scan_progress" ] [ text (ScanProgress.toString model.scan) ]
                 ]
             ]
             selectorprocess
@@ -2,8 +2,10 @@ module MeasurementCompact exposing (MeasurementCompact, decode, encode)
 
 import Device exposing (Device)
 import Json.Decode as Decode
-import Json.Decode.Pipeline exposing (required)
+import Json.Decode.Pipeline exposing (optional, required)
 import Json.Encode as Encode
+import Series exposing (Series)
+import Settings exposing (Settings)
 import TerminalParameter exposing (TerminalParameter)
 import TestDataCompact exposing (TestDataCompact)
 import TestParameter exposing (TestParameter)
@@ -12,6 +14,7 @@ import TimeStamp exposing (TimeStamp)
 
 type alias MeasurementCompact =
     { id : String
+    , alias : String
     , file_path : String
     , sheet_name : String
 
@@ -22,6 +25,8 @@ type alias MeasurementCompact =
     , test_parameter : TestParameter
     , test_time_stamp : TimeStamp
     , terminals : List TerminalParameter
+    , settings : Settings
+    , series : List Series
 
     --data
     , test_data : List TestDataCompact
@@ -32,12 +37,15 @@ encode : MeasurementCompact -> Encode.Value
 encode measurement =
     Encode.object
         [ ( "id", Encode.string measurement.id )
+        , ( "alias", Encode.string measurement.alias )
         , ( "file_path", Encode.string measurement.file_path )
         , ( "sheet_name", Encode.string measurement.sheet_name )
         , ( "device", Device.encode measurement.device )
         , ( "test_parameter", TestParameter.encode measurement.test_parameter )
         , ( "test_time_stamp", TimeStamp.encode measurement.test_time_stamp )
         , ( "teminals", Encode.list TerminalParameter.encode measurement.terminals )
+        , ( "settings", Settings.encode measurement.settings )
+        , ( "series", Encode.list Series.encode measurement.series )
         , ( "test_data", Encode.list TestDataCompact.encode measurement.test_data )
         ]
 
@@ -46,10 +54,13 @@ decode : Decode.Decoder MeasurementCompact
 decode =
     Decode.succeed MeasurementCompact
         |> required "id" Decode.string
+        |> optional "alias" Decode.string ""
         |> required "file_path" Decode.string
         |> required "sheet_name" Decode.string
         |> required "device" Device.decode
         |> required "test_parameter" TestParameter.decode
         |> required "test_time_stamp" TimeStamp.decode
         |> required "terminals" (Decode.list TerminalParameter.decode)
+        |> optional "settings" Settings.decode Settings.empty
+        |> optional "series" (Decode.list Series.decode) []
         |> required "test_data" (Decode.list TestDataCompact.decode)
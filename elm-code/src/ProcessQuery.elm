@@ -1,5 +1,6 @@
 module ProcessQuery exposing (ProcessQuery, encode)
 
+import EncodeHelper
 import Json.Encode as Encode
 import ProcessData exposing (ProcessData)
 import ProcessingType exposing (ProcessingType)
@@ -9,6 +10,7 @@ type alias ProcessQuery =
     { what : List ProcessingType
     , combined : Bool
     , from : List ProcessData
+    , collection : Maybe String
     }
 
 
@@ -18,4 +20,5 @@ encode query =
         [ ( "what", Encode.list ProcessingType.encode query.what )
         , ( "combined", Encode.bool query.combined )
         , ( "from", Encode.list ProcessData.encode query.from )
+        , ( "collection", EncodeHelper.maybe Encode.string query.collection )
         ]
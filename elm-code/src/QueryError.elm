@@ -0,0 +1,22 @@
+module QueryError exposing (QueryError, decode, toString)
+
+import Json.Decode as Decode
+import Json.Decode.Pipeline exposing (required)
+
+
+type alias QueryError =
+    { position : Int
+    , message : String
+    }
+
+
+decode : Decode.Decoder QueryError
+decode =
+    Decode.succeed QueryError
+        |> required "position" Decode.int
+        |> required "message" Decode.string
+
+
+toString : QueryError -> String
+toString error =
+    error.message ++ " at position " ++ String.fromInt error.position
@@ -1,4 +1,4 @@
-module RustTask exposing (RustTask, decode, init)
+module RustTask exposing (RustTask, decode, init, isScanning)
 
 import Json.Decode as Decode
 
@@ -7,6 +7,9 @@ type RustTask
     = Init
     | Filtering
     | Processing
+    | Scanning
+    | Scanned
+    | Saved
 
 
 init : RustTask
@@ -14,6 +17,11 @@ init =
     Init
 
 
+isScanning : RustTask -> Bool
+isScanning task =
+    task == Scanning
+
+
 decode : Decode.Decoder RustTask
 decode =
     Decode.string
@@ -29,6 +37,15 @@ decode =
                     "Processing" ->
                         Decode.succeed Processing
 
+                    "Scanning" ->
+                        Decode.succeed Scanning
+
+                    "Scanned" ->
+                        Decode.succeed Scanned
+
+                    "Saved" ->
+                        Decode.succeed Saved
+
                     _ ->
                         Decode.fail "Error parsing Task"
             )
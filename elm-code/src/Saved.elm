@@ -0,0 +1,81 @@
+module Saved exposing (Saved, SavedQuery(..), decode, empty, encodeQuery, isFree)
+
+import Dict exposing (Dict)
+import FilterQuery exposing (FilterQuery)
+import Json.Decode as Decode
+import Json.Decode.Pipeline exposing (optional)
+import Json.Encode as Encode
+
+
+
+-- a saved query is evaluated again when it is opened, a collection keeps the ids of its runs
+
+
+type SavedQuery
+    = FilterSaved FilterQuery
+    | TextSaved String
+
+
+type alias Saved =
+    { queries : Dict String SavedQuery
+    , collections : Dict String (List String)
+    }
+
+
+empty : Saved
+empty =
+    Saved Dict.empty Dict.empty
+
+
+decode : Decode.Decoder Saved
+decode =
+    Decode.succeed Saved
+        |> optional "queries" (Decode.dict decodeQuery) Dict.empty
+        |> optional "collections" (Decode.dict (Decode.list Decode.string)) Dict.empty
+
+
+decodeQuery : Decode.Decoder SavedQuery
+decodeQuery =
+    Decode.field "kind" Decode.string
+        |> Decode.andThen
+            (\kind ->
+                case kind of
+                    "Filter" ->
+                        Decode.map FilterSaved (Decode.field "query" FilterQuery.decode)
+
+                    "Text" ->
+                        Decode.map TextSaved (Decode.field "query" Decode.string)
+
+                    _ ->
+                        Decode.fail ("Error parsing saved query " ++ kind)
+            )
+
+
+encodeQuery : SavedQuery -> Encode.Value
+encodeQuery query =
+    case query of
+        FilterSaved filter ->
+            Encode.object [ ( "kind", Encode.string "Filter" ), ( "query", FilterQuery.encode filter ) ]
+
+        TextSaved text ->
+            Encode.object [ ( "kind", Encode.string "Text" ), ( "query", Encode.string text ) ]
+
+
+
+-- kind is "Query" or "Collection", a name is free when it is not blank and not taken by one of that kind
+
+
+isFree : String -> String -> Saved -> Bool
+isFree kind name saved =
+    let
+        trimmed =
+            String.trim name
+
+        taken =
+            if kind == "Query" then
+                Dict.member trimmed saved.queries
+
+            else
+                Dict.member trimmed saved.collections
+    in
+    not (String.isEmpty trimmed) && not taken
@@ -0,0 +1,46 @@
+module ScanProgress exposing (ScanProgress, decode, empty, toString)
+
+import Json.Decode as Decode
+import Json.Decode.Pipeline exposing (optional, required)
+
+
+type alias ScanProgress =
+    { running : Bool
+    , done : Int
+    , total : Int
+    , failed : Int
+    , file : String
+    , last_error : Maybe String
+    }
+
+
+empty : ScanProgress
+empty =
+    ScanProgress False 0 0 0 "" Nothing
+
+
+decode : Decode.Decoder ScanProgress
+decode =
+    Decode.succeed ScanProgress
+        |> required "running" Decode.bool
+        |> required "done" Decode.int
+        |> required "total" Decode.int
+        |> required "failed" Decode.int
+        |> required "file" Decode.string
+        |> optional "last_error" (Decode.nullable Decode.string) Nothing
+
+
+toString : ScanProgress -> String
+toString progress =
+    if progress.running then
+        "Scanning "
+            ++ String.fromInt progress.done
+            ++ "/"
+            ++ String.fromInt progress.total
+            ++ " files ("
+            ++ String.fromInt progress.failed
+            ++ " failed): "
+            ++ progress.file
+
+    else
+        Maybe.withDefault "" progress.last_error
@@ -0,0 +1,65 @@
+module Series exposing (Series, SteppedBias, decode, encode, label)
+
+import EncodeHelper
+import Json.Decode as Decode
+import Json.Decode.Pipeline exposing (required)
+import Json.Encode as Encode
+import List.Extra
+import Terminal exposing (Terminal)
+import Unit exposing (Unit)
+
+
+type alias Series =
+    { number : Int
+    , label : String
+    , bias : Maybe SteppedBias
+    }
+
+
+type alias SteppedBias =
+    { terminal : Terminal
+    , unit : Unit
+    , value : Float
+    }
+
+
+encode : Series -> Encode.Value
+encode series =
+    Encode.object
+        [ ( "number", Encode.int series.number )
+        , ( "label", Encode.string series.label )
+        , ( "bias", EncodeHelper.maybe encodeBias series.bias )
+        ]
+
+
+encodeBias : SteppedBias -> Encode.Value
+encodeBias bias =
+    Encode.object
+        [ ( "terminal", Terminal.encode bias.terminal )
+        , ( "unit", Unit.encode bias.unit )
+        , ( "value", Encode.float bias.value )
+        ]
+
+
+decode : Decode.Decoder Series
+decode =
+    Decode.succeed Series
+        |> required "number" Decode.int
+        |> required "label" Decode.string
+        |> required "bias" (Decode.nullable decodeBias)
+
+
+decodeBias : Decode.Decoder SteppedBias
+decodeBias =
+    Decode.succeed SteppedBias
+        |> required "terminal" Terminal.decode
+        |> required "unit" Unit.decode
+        |> required "value" Decode.float
+
+
+label : List Series -> Int -> String
+label series number =
+    series
+        |> List.Extra.find (\s -> s.number == number)
+        |> Maybe.map .label
+        |> Maybe.withDefault ("Series " ++ String.fromInt number)
@@ -0,0 +1,35 @@
+module Settings exposing (Settings, decode, empty, encode)
+
+import Dict exposing (Dict)
+import Json.Decode as Decode
+import Json.Decode.Pipeline exposing (optional, required)
+import Json.Encode as Encode
+
+
+type alias Settings =
+    { global : Dict String String
+    , terminals : List (Dict String String)
+    , formulas : List String
+    }
+
+
+encode : Settings -> Encode.Value
+encode settings =
+    Encode.object
+        [ ( "global", Encode.dict identity Encode.string settings.global )
+        , ( "terminals", Encode.list (Encode.dict identity Encode.string) settings.terminals )
+        , ( "formulas", Encode.list Encode.string settings.formulas )
+        ]
+
+
+decode : Decode.Decoder Settings
+decode =
+    Decode.succeed Settings
+        |> required "global" (Decode.dict Decode.string)
+        |> required "terminals" (Decode.list (Decode.dict Decode.string))
+        |> optional "formulas" (Decode.list Decode.string) []
+
+
+empty : Settings
+empty =
+    Settings Dict.empty [] []
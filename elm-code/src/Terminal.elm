@@ -1,4 +1,4 @@
-module Terminal exposing (Terminal, decode, encode, toString, toString_concise)
+module Terminal exposing (Terminal, decode, encode, other, toString, toString_concise)
 
 import Json.Decode as Decode
 import Json.Encode as Encode
@@ -10,6 +10,12 @@ type Terminal
     | Source
     | Bulk
     | Time
+    | Other
+
+
+other : Terminal
+other =
+    Other
 
 
 encode : Terminal -> Encode.Value
@@ -30,6 +36,9 @@ encode terminal =
         Time ->
             Encode.string "Time"
 
+        Other ->
+            Encode.string "Other"
+
 
 decode : Decode.Decoder Terminal
 decode =
@@ -52,6 +61,9 @@ decode =
                     "Time" ->
                         Decode.succeed Time
 
+                    "Other" ->
+                        Decode.succeed Other
+
                     _ ->
                         Decode.fail "Error parsing Terminal"
             )
@@ -75,6 +87,9 @@ toString terminal =
         Time ->
             "Time"
 
+        Other ->
+            "Other"
+
 
 toString_concise : Terminal -> String
 toString_concise terminal =
@@ -93,3 +108,6 @@ toString_concise terminal =
 
         Time ->
             "T"
+
+        Other ->
+            ""
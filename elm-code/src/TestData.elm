@@ -1,7 +1,7 @@
 module TestData exposing (TestData, decode, encode, toString, toString_concise)
 
 import Json.Decode as Decode
-import Json.Decode.Pipeline exposing (required)
+import Json.Decode.Pipeline exposing (optional, required)
 import Json.Encode as Encode
 import Terminal exposing (Terminal)
 import Unit exposing (Unit)
@@ -10,6 +10,7 @@ import Unit exposing (Unit)
 type alias TestData =
     { terminal : Terminal
     , unit : Unit
+    , name : String
     , data : List (List Float)
     }
 
@@ -19,6 +20,7 @@ encode test_data =
     Encode.object
         [ ( "terminal", Terminal.encode test_data.terminal )
         , ( "unit", Unit.encode test_data.unit )
+        , ( "name", Encode.string test_data.name )
         , ( "data", Encode.list (Encode.list Encode.float) test_data.data )
         ]
 
@@ -28,14 +30,23 @@ decode =
     Decode.succeed TestData
         |> required "terminal" Terminal.decode
         |> required "unit" Unit.decode
-        |> required "count" (Decode.list (Decode.list Decode.float))
+        |> optional "name" Decode.string ""
+        |> required "data" (Decode.list (Decode.list Decode.float))
 
 
 toString : TestData -> String
 toString test_data =
-    Terminal.toString test_data.terminal ++ " " ++ Unit.toString test_data.unit
+    if test_data.terminal == Terminal.other then
+        test_data.name
+
+    else
+        Terminal.toString test_data.terminal ++ " " ++ Unit.toString test_data.unit
 
 
 toString_concise : TestData -> String
 toString_concise test_data =
-    Unit.toString_concise test_data.unit ++ Terminal.toString_concise test_data.terminal
+    if test_data.terminal == Terminal.other then
+        test_data.name
+
+    else
+        Unit.toString_concise test_data.unit ++ Terminal.toString_concise test_data.terminal
@@ -1,7 +1,7 @@
 module TestDataCompact exposing (TestDataCompact, decode, encode, toString, toString_concise)
 
 import Json.Decode as Decode
-import Json.Decode.Pipeline exposing (required)
+import Json.Decode.Pipeline exposing (optional, required)
 import Json.Encode as Encode
 import Terminal exposing (Terminal)
 import Unit exposing (Unit)
@@ -10,7 +10,9 @@ import Unit exposing (Unit)
 type alias TestDataCompact =
     { terminal : Terminal
     , unit : Unit
+    , name : String
     , count : Int
+    , series : List Int
     }
 
 
@@ -19,7 +21,9 @@ encode test_data =
     Encode.object
         [ ( "terminal", Terminal.encode test_data.terminal )
         , ( "unit", Unit.encode test_data.unit )
+        , ( "name", Encode.string test_data.name )
         , ( "count", Encode.int test_data.count )
+        , ( "series", Encode.list Encode.int test_data.series )
         ]
 
 
@@ -28,14 +32,24 @@ decode =
     Decode.succeed TestDataCompact
         |> required "terminal" Terminal.decode
         |> required "unit" Unit.decode
+        |> optional "name" Decode.string ""
         |> required "count" Decode.int
+        |> optional "series" (Decode.list Decode.int) []
 
 
 toString : TestDataCompact -> String
 toString test_data =
-    Terminal.toString test_data.terminal ++ " " ++ Unit.toString test_data.unit
+    if test_data.terminal == Terminal.other then
+        test_data.name
+
+    else
+        Terminal.toString test_data.terminal ++ " " ++ Unit.toString test_data.unit
 
 
 toString_concise : TestDataCompact -> String
 toString_concise test_data =
-    Unit.toString_concise test_data.unit ++ Terminal.toString_concise test_data.terminal
+    if test_data.terminal == Terminal.other then
+        test_data.name
+
+    else
+        Unit.toString_concise test_data.unit ++ Terminal.toString_concise test_data.terminal
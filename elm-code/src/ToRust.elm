@@ -1,8 +1,9 @@
-module ToRust exposing (filter, init, log, process)
+module ToRust exposing (collection, delete, filter, init, log, process, query, rename, saveCollection, saveQuery)
 
 import FilterQuery exposing (FilterQuery)
 import Json.Encode as Encode
 import ProcessQuery exposing (ProcessQuery)
+import Saved exposing (SavedQuery)
 
 
 init : Encode.Value
@@ -32,3 +33,55 @@ process query =
         [ ( "torust", Encode.string "Process" )
         , ( "content", ProcessQuery.encode query )
         ]
+
+
+query : String -> Encode.Value
+query text =
+    Encode.object
+        [ ( "torust", Encode.string "Query" )
+        , ( "content", Encode.string text )
+        ]
+
+
+saveQuery : String -> SavedQuery -> Encode.Value
+saveQuery name saved =
+    Encode.object
+        [ ( "torust", Encode.string "SaveQuery" )
+        , ( "content", Encode.object [ ( "name", Encode.string name ), ( "query", Saved.encodeQuery saved ) ] )
+        ]
+
+
+saveCollection : String -> List String -> Encode.Value
+saveCollection name ids =
+    Encode.object
+        [ ( "torust", Encode.string "SaveCollection" )
+        , ( "content", Encode.object [ ( "name", Encode.string name ), ( "ids", Encode.list Encode.string ids ) ] )
+        ]
+
+
+
+-- kind is "Query" or "Collection"
+
+
+rename : String -> String -> String -> Encode.Value
+rename kind name to =
+    Encode.object
+        [ ( "torust", Encode.string "Rename" )
+        , ( "content", Encode.object [ ( "kind", Encode.string kind ), ( "name", Encode.string name ), ( "to", Encode.string to ) ] )
+        ]
+
+
+delete : String -> String -> Encode.Value
+delete kind name =
+    Encode.object
+        [ ( "torust", Encode.string "Delete" )
+        , ( "content", Encode.object [ ( "kind", Encode.string kind ), ( "name", Encode.string name ) ] )
+        ]
+
+
+collection : String -> Encode.Value
+collection name =
+    Encode.object
+        [ ( "torust", Encode.string "Collection" )
+        , ( "content", Encode.string name )
+        ]
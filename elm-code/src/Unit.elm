@@ -8,6 +8,7 @@ type Unit
     = Voltage
     | Current
     | Seconds
+    | Other
 
 
 encode : Unit -> Encode.Value
@@ -22,6 +23,9 @@ encode unit =
         Seconds ->
             Encode.string "Seconds"
 
+        Other ->
+            Encode.string "Other"
+
 
 decode : Decode.Decoder Unit
 decode =
@@ -38,6 +42,9 @@ decode =
                     "Seconds" ->
                         Decode.succeed Seconds
 
+                    "Other" ->
+                        Decode.succeed Other
+
                     _ ->
                         Decode.fail "Error parsing Terminal"
             )
@@ -55,6 +62,9 @@ toString unit =
         Seconds ->
             "(s)"
 
+        Other ->
+            ""
+
 
 toString_concise : Unit -> String
 toString_concise unit =
@@ -67,3 +77,6 @@ toString_concise unit =
 
         Seconds ->
             ""
+
+        Other ->
+            ""
@@ -1,3 +1,4 @@
+use crate::ingest::{ErrorKind, IngestError};
 use calamine::*;
 
 //calamine helper struct
@@ -5,13 +6,15 @@ use calamine::*;
 pub struct MyRange {
     pub it: Range<DataType>,
     abs_pos: (u32, u32),
+    sheet: String,
 }
 
 impl MyRange {
-    pub fn new(range: Range<DataType>) -> MyRange {
+    pub fn new(range: Range<DataType>, sheet: &str) -> MyRange {
         MyRange {
             it: range,
             abs_pos: (0, 0),
+            sheet: sheet.to_string(),
         }
     }
 
@@ -27,6 +30,7 @@ impl MyRange {
                 (self.abs_pos.0 + end.0 as u32, self.abs_pos.1 + end.1 as u32),
             ),
             abs_pos,
+            sheet: self.sheet.clone(),
         }
     }
 
@@ -36,4 +40,31 @@ impl MyRange {
             self.it.end().unwrap().1 as usize,
         )
     }
+
+    pub fn sheet_name(&self) -> &str {
+        self.sheet.as_str()
+    }
+
+    //position in the sheet of a cell relative to this range
+    pub fn absolute(&self, pos: (usize, usize)) -> (u32, u32) {
+        (self.abs_pos.0 + pos.0 as u32, self.abs_pos.1 + pos.1 as u32)
+    }
+
+    //error located at a cell relative to this range
+    pub fn error_at(&self, pos: (usize, usize), error: IngestError) -> IngestError {
+        error.in_sheet(self.sheet.as_str()).at(self.absolute(pos))
+    }
+
+    pub fn get_string(&self, pos: (usize, usize)) -> Result<&str, IngestError> {
+        match self.it.get(pos) {
+            Some(DataType::String(string)) => Ok(string.as_str()),
+            Some(DataType::Empty) | None => {
+                Err(self.error_at(pos, IngestError::new(ErrorKind::MissingCell)))
+            }
+            Some(other) => Err(self.error_at(
+                pos,
+                IngestError::unexpected("text", other.to_string().as_str()),
+            )),
+        }
+    }
 }
@@ -0,0 +1,53 @@
+use crate::measurement::process::{ProcessDefinition, ProcessRegistry};
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+//user settings read from a json file, every part is optional
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Config {
+    //mapping profiles for csv/txt exports of other instruments
+    #[serde(default)]
+    pub csv_profiles: Vec<crate::ingest::csv::Profile>,
+    //how process, die, temperature and further device fields are read from the file path
+    #[serde(default)]
+    pub metadata: crate::measurement::metadata::MetadataSchema,
+    //the processes of the project with their technology data
+    #[serde(default = "default_processes")]
+    pub processes: Vec<ProcessDefinition>,
+    //where and how the samples are kept
+    #[serde(default)]
+    pub store: crate::store::StoreConfig,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            csv_profiles: vec![],
+            metadata: Default::default(),
+            processes: default_processes(),
+            store: Default::default(),
+        }
+    }
+}
+
+fn default_processes() -> Vec<ProcessDefinition> {
+    vec![
+        ProcessDefinition::new("MINOXG"),
+        ProcessDefinition::new("GF22"),
+    ]
+}
+
+impl Config {
+    //a missing file gives the defaults, an unreadable one is reported
+    pub fn load(path: &str) -> Result<Config, String> {
+        match fs::read_to_string(path) {
+            Ok(string) => serde_json::from_str::<Config>(string.as_str())
+                .map_err(|e| format!("invalid config {}: {}", path, e)),
+            Err(_) => Ok(Config::default()),
+        }
+    }
+
+    pub fn process_registry(&self) -> ProcessRegistry {
+        ProcessRegistry::new(&self.processes)
+    }
+}
@@ -0,0 +1,260 @@
+use super::{Database, ScannedFile};
+use crate::measurement::testdata::TestData;
+use crate::measurement::MeasurementCompact;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io;
+
+//outcome of merging another library into this one
+#[derive(Debug, Default)]
+pub struct MergeReport {
+    pub added: usize,
+    //runs this library already holds, from the same file content or the same sheet and time stamp
+    pub duplicates: usize,
+    //ids that were taken in this library, with the id the run got instead
+    pub renamed: Vec<(String, String)>,
+    pub files_added: usize,
+    //paths that hold a different file in each library, the runs of this library are kept
+    pub conflicting_files: Vec<String>,
+    //runs of the other library read from a conflicting file, they are left out
+    pub conflicting: usize,
+    //saved queries and collections of the other library that were not taken over, with the reason
+    pub saved_left_out: Vec<String>,
+    //ids in collections of the other library whose run was not merged, they are left out
+    pub collection_runs_left_out: usize,
+}
+
+//the same content, or the same path when a fingerprint is missing
+fn same_file(ours: &ScannedFile, theirs: &ScannedFile) -> bool {
+    match (ours.fingerprint.as_ref(), theirs.fingerprint.as_ref()) {
+        (Some(ours), Some(theirs)) => ours.hash == theirs.hash,
+        _ => ours.path == theirs.path,
+    }
+}
+
+//the same sheet and time stamp of a file at `path` in this library
+fn same_run(ours: &MeasurementCompact, path: &str, theirs: &MeasurementCompact) -> bool {
+    ours.file_path == path
+        && ours.sheet_name == theirs.sheet_name
+        && ours.test_time_stamp == theirs.test_time_stamp
+}
+
+impl Database {
+    //takes over the runs of `other` that this library does not hold yet, `samples` reads the
+    //samples of a run of `other` that are not in its unsaved samples
+    pub fn merge(
+        &mut self,
+        mut other: Database,
+        samples: &dyn Fn(&MeasurementCompact) -> io::Result<Vec<TestData>>,
+    ) -> io::Result<MergeReport> {
+        let mut report = MergeReport::default();
+        //their path and the path of the same content in this library
+        let mut duplicate_files: HashMap<String, String> = HashMap::new();
+        let mut conflicting_files = HashSet::new();
+        for file in other.files.drain(..) {
+            if let Some(ours) = self.files.iter().find(|ours| same_file(ours, &file)) {
+                duplicate_files.insert(file.path, ours.path.clone());
+            } else if self.file(&file.path).is_some() {
+                conflicting_files.insert(file.path.clone());
+                report.conflicting_files.push(file.path);
+            } else {
+                report.files_added += 1;
+                self.files.push(file);
+            }
+        }
+        //aliases given from here on do not repeat those of either library
+        for (day, counter) in other.id_day_counter.iter() {
+            let ours = self.id_day_counter.entry(day.clone()).or_insert(0);
+            *ours = (*ours).max(*counter);
+        }
+        let mut aliases: HashSet<String> =
+            self.measurements.iter().map(|m| m.alias.clone()).collect();
+        let mut taken = self.ids();
+        let mut ids: HashMap<String, String> = HashMap::new();
+        for mut measurement in other.measurements.drain(..) {
+            //they would be taken for runs of our file and removed when it is read again
+            if conflicting_files.contains(&measurement.file_path) {
+                report.conflicting += 1;
+                continue;
+            }
+            //a duplicate is known by the id of our run, so the saved entries of `other` find it
+            let path = duplicate_files
+                .get(&measurement.file_path)
+                .unwrap_or(&measurement.file_path);
+            let ours = self
+                .measurements
+                .iter()
+                .find(|ours| same_run(ours, path, &measurement));
+            if ours.is_some() || duplicate_files.contains_key(&measurement.file_path) {
+                if let Some(ours) = ours {
+                    ids.insert(measurement.id, ours.id.clone());
+                }
+                report.duplicates += 1;
+                continue;
+            }
+            let test_data = match other.unsaved.remove(&measurement.id) {
+                Some(test_data) => test_data,
+                None => samples(&measurement)?,
+            };
+            let old = measurement.id.clone();
+            if !taken.insert(measurement.id.clone()) {
+                measurement.id = super::unique_id(&mut taken, &measurement);
+                report.renamed.push((old.clone(), measurement.id.clone()));
+            }
+            if measurement.alias.is_empty() || aliases.contains(&measurement.alias) {
+                measurement.alias = self.generate_alias(measurement.test_time_stamp);
+            }
+            aliases.insert(measurement.alias.clone());
+            ids.insert(old, measurement.id.clone());
+            self.unsaved.insert(measurement.id.clone(), test_data);
+            self.changed.insert(measurement.id.clone());
+            self.measurements.push(measurement);
+            report.added += 1;
+        }
+        for (old, id) in other.migrated_ids {
+            if let Some(id) = ids.get(&id) {
+                self.migrated_ids.entry(old).or_insert_with(|| id.clone());
+            }
+        }
+        let (left_out, runs_left_out) = self.saved.merge(other.saved, &ids);
+        report.saved_left_out = left_out;
+        report.collection_runs_left_out = runs_left_out;
+        self.revision = super::next_revision();
+        Ok(report)
+    }
+
+    //a library of its own for the given runs, with the files they were read from
+    pub fn extract(&self, ids: &[String]) -> Database {
+        let measurements: Vec<MeasurementCompact> = self
+            .measurements
+            .iter()
+            .filter(|m| ids.contains(&m.id))
+            .cloned()
+            .collect();
+        let paths: HashSet<&str> = measurements.iter().map(|m| m.file_path.as_str()).collect();
+        let mut database = Database::new();
+        database.files = self
+            .files
+            .iter()
+            .filter(|file| paths.contains(file.path.as_str()))
+            .cloned()
+            .collect();
+        database.id_day_counter = self.id_day_counter.clone();
+        database.migrated_ids = self
+            .migrated_ids
+            .iter()
+            .filter(|(_, id)| ids.contains(id))
+            .map(|(old, id)| (old.clone(), id.clone()))
+            .collect::<BTreeMap<_, _>>();
+        database.ingest_report = self
+            .ingest_report
+            .iter()
+            .filter(|report| paths.contains(report.file.as_str()))
+            .cloned()
+            .collect();
+        database.changed = measurements.iter().map(|m| m.id.clone()).collect();
+        database.measurements = measurements;
+        database
+    }
+
+    //the id of a run given by its id, alias or an id it had in an older library
+    pub fn resolve_id(&self, name: &str) -> Option<String> {
+        self.measurements
+            .iter()
+            .find(|m| m.id == name || m.alias == name)
+            .map(|m| m.id.clone())
+            .or_else(|| self.migrated_ids.get(name).cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::saved::SavedQuery;
+    use crate::elm::facets::tests::run;
+    use crate::ingest::fingerprint::Fingerprint;
+
+    //a library with one file of the given content and the runs read from it, runs that differ
+    //by a multiple of 20 and are below 50 share their file
+    fn library(hash: &str, runs: &[usize]) -> Database {
+        let mut database = Database::new();
+        let runs: Vec<MeasurementCompact> = runs.iter().map(|n| run(*n)).collect();
+        database.files.push(ScannedFile {
+            path: runs[0].file_path.clone(),
+            fingerprint: Some(Fingerprint {
+                size: 1,
+                modified: 1,
+                hash: hash.to_string(),
+            }),
+            vanished: false,
+        });
+        database.measurements = runs;
+        database
+    }
+
+    #[test]
+    fn runs_of_a_differing_file_are_left_out() {
+        let mut ours = library("a", &[0, 20]);
+        let report = ours.merge(library("b", &[0, 40]), &|_| Ok(vec![])).unwrap();
+        assert_eq!(report.conflicting_files, vec![ours.files[0].path.clone()]);
+        assert_eq!((report.added, report.conflicting), (0, 2));
+        assert_eq!(ours.measurements.len(), 2);
+
+        let report = ours
+            .merge(library("a", &[0, 20, 40]), &|_| Ok(vec![]))
+            .unwrap();
+        assert_eq!((report.added, report.duplicates), (0, 3));
+    }
+
+    //collections of the other library list the runs by their id in this library
+    #[test]
+    fn collections_follow_the_merged_runs() {
+        let mut ours = library("a", &[0, 20]);
+        ours.saved.collections.insert("mine".to_string(), vec![]);
+        ours.saved
+            .queries
+            .insert("q".to_string(), SavedQuery::Text("T = 4K".to_string()));
+        let mut theirs = library("b", &[0, 40, 5]);
+        let their_ids: Vec<String> = theirs.measurements.iter().map(|m| m.id.clone()).collect();
+        theirs
+            .saved
+            .collections
+            .insert("runs".to_string(), their_ids.clone());
+        theirs
+            .saved
+            .collections
+            .insert("mine".to_string(), vec![their_ids[2].clone()]);
+        theirs
+            .saved
+            .queries
+            .insert("q".to_string(), SavedQuery::Text("T = 14K".to_string()));
+        let report = ours.merge(theirs, &|_| Ok(vec![])).unwrap();
+        assert_eq!((report.added, report.conflicting), (1, 2));
+        assert_eq!(ours.saved.collections["runs"], vec![their_ids[2].clone()]);
+        assert!(ours.saved.collections["mine"].is_empty());
+        assert_eq!(
+            report.saved_left_out,
+            vec![
+                "query q: the name is taken",
+                "collection mine: the name is taken"
+            ]
+        );
+        assert_eq!(report.collection_runs_left_out, 2);
+
+        //the same file under another path
+        let mut ours = library("a", &[0, 20]);
+        let mut theirs = library("a", &[0]);
+        theirs.files[0].path = "moved/runs#0.xls".to_string();
+        theirs.measurements[0].file_path = "moved/runs#0.xls".to_string();
+        theirs.measurements[0].id = "20200101-moved".to_string();
+        theirs
+            .saved
+            .collections
+            .insert("moved".to_string(), vec!["20200101-moved".to_string()]);
+        let report = ours.merge(theirs, &|_| Ok(vec![])).unwrap();
+        assert_eq!((report.added, report.duplicates), (0, 1));
+        assert_eq!(
+            ours.saved.collections["moved"],
+            vec![ours.measurements[0].id.clone()]
+        );
+    }
+}
@@ -0,0 +1,313 @@
+use crate::measurement::testdata::TestData;
+use crate::measurement::timestamp::TimeStamp;
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet};
+
+//layout of result.json written by this version
+//1: samples inside result.json, file paths as written by the os, plain list of scanned files
+//2: normalized paths, scanned files with fingerprints
+//3: stable ids, the day counter ids kept as aliases
+//4: samples in the store, result.json is the index
+pub const SCHEMA_VERSION: u64 = 4;
+
+//samples taken out of result.json, by id
+pub type Samples = HashMap<String, Vec<TestData>>;
+
+type Step = fn(&mut Value, &mut Samples) -> Result<(), String>;
+
+//STEPS[n] migrates layout n + 1 to n + 2
+const STEPS: [Step; 3] = [normalize_paths, stable_ids, samples_to_store];
+
+fn has_inline_samples(value: &Value) -> bool {
+    value["measurements"]
+        .as_array()
+        .is_some_and(|measurements| {
+            measurements.iter().any(|measurement| {
+                measurement["test_data"]
+                    .as_array()
+                    .is_some_and(|test_data| test_data.iter().any(|t| t.get("data").is_some()))
+            })
+        })
+}
+
+//files written before the version was stored are recognized by their layout, libraries without
+//any channel read the same in layout 3 and 4
+pub fn version_of(value: &Value) -> u64 {
+    if let Some(version) = value["schema_version"].as_u64() {
+        return version.max(1);
+    }
+    let has = |key: &str| value.get(key).is_some();
+    match (has("files"), has("migrated_ids")) {
+        (false, _) => 1,
+        (true, false) => 2,
+        (true, true) if has_inline_samples(value) => 3,
+        (true, true) => 4,
+    }
+}
+
+//brings a result.json of any earlier layout to the current one, returns the version it had
+pub fn migrate(value: &mut Value, samples: &mut Samples) -> Result<u64, String> {
+    if !value.is_object() {
+        return Err("not a library".to_string());
+    }
+    let version = version_of(value);
+    if version > SCHEMA_VERSION {
+        return Err(format!(
+            "written by a newer version with schema {}, this one reads up to schema {}",
+            version, SCHEMA_VERSION
+        ));
+    }
+    for (index, step) in STEPS.iter().enumerate().skip(version as usize - 1) {
+        step(value, samples).map_err(|e| {
+            format!("migration from schema {} to {}: {}", index + 1, index + 2, e)
+        })?;
+    }
+    value["schema_version"] = json!(SCHEMA_VERSION);
+    Ok(version)
+}
+
+//indexing with [] would add missing fields as null
+fn normalize_field(value: &mut Value, field: &str) {
+    if let Some(path) = value.get_mut(field) {
+        if let Some(normalized) = path.as_str().map(crate::ingest::path::normalize) {
+            *path = json!(normalized);
+        }
+    }
+}
+
+//"\dir\file.xls" on windows and "/dir/file.xls" on linux both become "dir/file.xls",
+//the plain list of scanned files is taken over without fingerprints
+fn normalize_paths(value: &mut Value, _: &mut Samples) -> Result<(), String> {
+    let before: Vec<String> = match value
+        .as_object_mut()
+        .and_then(|object| object.remove("files_scanned_before"))
+    {
+        Some(Value::Array(files)) => files
+            .iter()
+            .filter_map(|file| file.as_str())
+            .map(crate::ingest::path::normalize)
+            .collect(),
+        _ => vec![],
+    };
+    if !value["files"].is_array() {
+        value["files"] = json!([]);
+    }
+    let files = value["files"].as_array_mut().unwrap();
+    for file in files.iter_mut() {
+        normalize_field(file, "path");
+    }
+    for path in before {
+        if !files.iter().any(|file| file["path"] == path.as_str()) {
+            files.push(json!({ "path": path, "fingerprint": null, "vanished": false }));
+        }
+    }
+    for (key, field) in [("measurements", "file_path"), ("ingest_report", "file")].iter() {
+        for entry in value
+            .get_mut(*key)
+            .and_then(|entries| entries.as_array_mut())
+            .into_iter()
+            .flatten()
+        {
+            normalize_field(entry, field);
+        }
+    }
+    Ok(())
+}
+
+//the counter based ids become aliases of ids derived from file, sheet and time stamp
+fn stable_ids(value: &mut Value, _: &mut Samples) -> Result<(), String> {
+    let mut migrated = serde_json::Map::new();
+    let measurements = value
+        .get_mut("measurements")
+        .and_then(|measurements| measurements.as_array_mut())
+        .ok_or("no measurements")?;
+    let aliased = |measurement: &Value| {
+        measurement["alias"]
+            .as_str()
+            .is_some_and(|alias| !alias.is_empty())
+    };
+    let mut taken: HashSet<String> = measurements
+        .iter()
+        .filter(|m| aliased(m))
+        .filter_map(|m| m["id"].as_str().map(|id| id.to_string()))
+        .collect();
+    for measurement in measurements.iter_mut().filter(|m| !aliased(m)) {
+        let old = measurement["id"].as_str().ok_or("measurement without id")?.to_string();
+        let time: TimeStamp = serde_json::from_value(measurement["test_time_stamp"].clone())
+            .map_err(|e| format!("{}: {}", old, e))?;
+        let stable = crate::measurement::stable_id(
+            measurement["file_path"].as_str().unwrap_or(""),
+            measurement["sheet_name"].as_str().unwrap_or(""),
+            time,
+        );
+        let mut id = stable.clone();
+        let mut n = 1;
+        while taken.contains(&id) {
+            n += 1;
+            id = format!("{}-{}", stable, n);
+        }
+        taken.insert(id.clone());
+        measurement["id"] = json!(id);
+        measurement["alias"] = json!(old);
+        migrated.insert(old, json!(id));
+    }
+    if !value["migrated_ids"].is_object() {
+        value["migrated_ids"] = json!({});
+    }
+    value["migrated_ids"]
+        .as_object_mut()
+        .unwrap()
+        .extend(migrated);
+    Ok(())
+}
+
+//the samples are handed to the store, the index keeps what the channels hold
+fn samples_to_store(value: &mut Value, samples: &mut Samples) -> Result<(), String> {
+    let measurements = value
+        .get_mut("measurements")
+        .and_then(|measurements| measurements.as_array_mut())
+        .ok_or("no measurements")?;
+    for measurement in measurements.iter_mut() {
+        let id = measurement["id"].as_str().ok_or("measurement without id")?.to_string();
+        let test_data: Vec<TestData> = serde_json::from_value(measurement["test_data"].take())
+            .map_err(|e| format!("{}: {}", id, e))?;
+        measurement["test_data"] = json!(test_data
+            .iter()
+            .map(|t| t.to_compact())
+            .collect::<Vec<_>>());
+        samples.insert(id, test_data);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::Database;
+
+    //three runs of output/result.json with three samples per vector
+    fn v1() -> Value {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/library/result_v1.json"
+        );
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    fn ids(value: &Value) -> Vec<&str> {
+        value["measurements"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|m| m["id"].as_str().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn every_step_gives_the_next_layout() {
+        let mut value = v1();
+        let mut samples = Samples::new();
+        assert_eq!(version_of(&value), 1);
+
+        normalize_paths(&mut value, &mut samples).unwrap();
+        assert_eq!(version_of(&value), 2);
+        assert!(value.get("files_scanned_before").is_none());
+        let files: Vec<&str> = value["files"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|file| file["path"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            files,
+            vec![
+                "Process=MINOXG Die=A1 T=293k/empty folder/time-id#1.xls",
+                "Process=MINOXG Die=A1 T=293k/vds-id#1.xls",
+                "Process=MINOXG Die=A1 T=293k/vtlin#1.xls"
+            ]
+        );
+        assert!(value["files"][0]["fingerprint"].is_null());
+        assert_eq!(
+            value["measurements"][2]["file_path"],
+            "Process=MINOXG Die=A1 T=293k/vtlin#1.xls"
+        );
+
+        stable_ids(&mut value, &mut samples).unwrap();
+        assert_eq!(version_of(&value), 3);
+        let old = ["20191204-1", "20191204-2", "20191121-1"];
+        for (n, id) in ids(&value).iter().enumerate() {
+            assert_eq!(value["measurements"][n]["alias"], old[n]);
+            assert_eq!(value["migrated_ids"][old[n]], *id);
+            assert!(id.starts_with(&old[n][..9]) && id.len() == 17, "{}", id);
+        }
+
+        samples_to_store(&mut value, &mut samples).unwrap();
+        assert_eq!(version_of(&value), 4);
+        let first = ids(&value)[0].to_string();
+        assert_eq!(samples.len(), 3);
+        assert_eq!(samples[&first].len(), 5);
+        assert!(samples[&first].iter().all(|t| t.data.len() == 1));
+        assert_eq!(samples[&first][0].data[0].len(), 3);
+        assert_eq!(value["measurements"][0]["test_data"][0]["count"], 1);
+    }
+
+    #[test]
+    fn migrates_to_a_library_of_this_version() {
+        let json = serde_json::to_string(&v1()).unwrap();
+        let (database, version) = Database::load(&json).unwrap();
+        assert_eq!(version, 1);
+        assert_eq!(database.schema_version, SCHEMA_VERSION);
+        assert_eq!(database.measurements.len(), 3);
+        assert_eq!(database.unsaved.len(), 3);
+        assert_eq!(database.migrated_ids.len(), 3);
+        assert_eq!(database.id_day_counter["20191204"], 21);
+
+        //a library of this version is taken as it is
+        let json = serde_json::to_string(&database).unwrap();
+        let (again, version) = Database::load(&json).unwrap();
+        assert_eq!(version, SCHEMA_VERSION);
+        assert_eq!(again.migrated_ids, database.migrated_ids);
+        assert!(again.unsaved.is_empty());
+    }
+
+    //libraries without samples used to be taken for layout 4, so their ids were never migrated
+    #[test]
+    fn libraries_without_samples_get_stable_ids() {
+        let empty = json!({"files": [], "id_day_counter": {}, "measurements": []});
+        assert_eq!(version_of(&empty), 2);
+        let mut migrated = empty.clone();
+        assert_eq!(migrate(&mut migrated, &mut Samples::new()), Ok(2));
+        assert_eq!(migrated["migrated_ids"], json!({}));
+
+        let mut value = v1();
+        normalize_paths(&mut value, &mut Samples::new()).unwrap();
+        for measurement in value["measurements"].as_array_mut().unwrap() {
+            measurement["test_data"] = json!([]);
+        }
+        assert_eq!(version_of(&value), 2);
+        migrate(&mut value, &mut Samples::new()).unwrap();
+        assert_eq!(value["measurements"][0]["alias"], "20191204-1");
+        assert_eq!(value["migrated_ids"]["20191204-1"], ids(&value)[0]);
+
+        let mut v3 = empty;
+        v3["migrated_ids"] = json!({});
+        assert_eq!(version_of(&v3), 4);
+    }
+
+    #[test]
+    fn newer_and_broken_libraries_are_refused() {
+        let mut newer = json!({"schema_version": SCHEMA_VERSION + 1, "measurements": []});
+        assert!(migrate(&mut newer, &mut Samples::new())
+            .unwrap_err()
+            .contains("newer version"));
+        assert_eq!(
+            migrate(&mut json!([]), &mut Samples::new()),
+            Err("not a library".to_string())
+        );
+        let mut value = v1();
+        value["measurements"][1]["test_time_stamp"] = json!("yesterday");
+        assert!(migrate(&mut value, &mut Samples::new())
+            .unwrap_err()
+            .starts_with("migration from schema 2 to 3: 20191204-2"));
+    }
+}
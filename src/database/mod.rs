@@ -0,0 +1,264 @@
+use crate::ingest::fingerprint::Fingerprint;
+use crate::ingest::FileReport;
+use crate::measurement::process::ProcessRegistry;
+use crate::measurement::testdata::TestData;
+use crate::measurement::timestamp::TimeStamp;
+use crate::measurement::{Measurement, MeasurementCompact};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+pub mod merge;
+
+pub mod migration;
+
+pub mod saved;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ScannedFile {
+    pub path: String,
+    //None for files taken over from an older library, they are read again at the next scan
+    pub fingerprint: Option<Fingerprint>,
+    //no longer found in the library directory
+    #[serde(default)]
+    pub vanished: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Database {
+    //layout of the file, see migration
+    pub schema_version: u64,
+    //every file the measurements were read from
+    #[serde(default)]
+    pub files: Vec<ScannedFile>,
+    //numbers the display aliases per day
+    pub id_day_counter: HashMap<String, u32>,
+    //"YYYYMMDD-n" ids of older libraries and the stable id they were replaced with
+    #[serde(default)]
+    pub migrated_ids: BTreeMap<String, String>,
+    //the index of the library, the samples of every run are kept in the store
+    pub measurements: Vec<MeasurementCompact>,
+    //outcome per file of the last scan
+    #[serde(default)]
+    pub ingest_report: Vec<FileReport>,
+    //samples of new or re-extracted runs by id, until they are written to the store
+    #[serde(skip)]
+    pub unsaved: HashMap<String, Vec<TestData>>,
+    //ids of runs that were removed, their samples are deleted from the store
+    #[serde(skip)]
+    pub discarded: Vec<String>,
+    //saved queries and collections of runs
+    #[serde(default)]
+    pub saved: saved::Saved,
+    //ids of runs whose index entry changed since the last save
+    #[serde(skip)]
+    pub changed: BTreeSet<String>,
+    //differs between any two states of the runs, copies share it until one of them changes
+    #[serde(skip, default = "next_revision")]
+    pub revision: u64,
+}
+
+static REVISIONS: AtomicU64 = AtomicU64::new(0);
+
+pub fn next_revision() -> u64 {
+    REVISIONS.fetch_add(1, Ordering::Relaxed)
+}
+
+impl Database {
+    pub fn new() -> Database {
+        Database {
+            schema_version: migration::SCHEMA_VERSION,
+            files: vec![],
+            id_day_counter: HashMap::new(),
+            migrated_ids: BTreeMap::new(),
+            measurements: vec![],
+            ingest_report: vec![],
+            unsaved: HashMap::new(),
+            discarded: vec![],
+            saved: saved::Saved::default(),
+            changed: BTreeSet::new(),
+            revision: next_revision(),
+        }
+    }
+
+    //reads a result.json of this or an earlier layout, returns the library and the version it had
+    pub fn load(json: &str) -> Result<(Database, u64), String> {
+        let mut value: serde_json::Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        let mut samples = HashMap::new();
+        let version = migration::migrate(&mut value, &mut samples)?;
+        let mut database: Database = serde_json::from_value(value).map_err(|e| e.to_string())?;
+        database.unsaved = samples;
+        Ok((database, version))
+    }
+
+    //adds the measurements of one file, returns how many were added
+    pub fn insert(&mut self, measurements: Vec<Measurement>) -> usize {
+        let amount = measurements.len();
+        let mut taken = self.ids();
+        for measurement in measurements {
+            let (mut measurement, test_data) = measurement.split();
+            measurement.id = unique_id(&mut taken, &measurement);
+            measurement.alias = self.generate_alias(measurement.test_time_stamp);
+            self.unsaved.insert(measurement.id.clone(), test_data);
+            self.changed.insert(measurement.id.clone());
+            self.measurements.push(measurement);
+        }
+        self.revision = next_revision();
+        amount
+    }
+
+    //re-extracted measurements of a modified file keep the id and alias of the stored run with the same
+    //time stamp, runs that are gone are removed, returns (added, updated, removed)
+    pub fn replace(
+        &mut self,
+        file: &str,
+        measurements: Vec<Measurement>,
+    ) -> (usize, usize, usize) {
+        //the ids of the old runs are kept out as well, they may be given to re-extracted runs
+        let mut taken = self.ids();
+        let (mut old, kept): (Vec<_>, Vec<_>) = self
+            .measurements
+            .drain(..)
+            .partition(|measurement| measurement.file_path == file);
+        self.measurements = kept;
+        let (mut added, mut updated) = (0, 0);
+        for measurement in measurements {
+            let (mut measurement, test_data) = measurement.split();
+            //the same sheet is preferred when several runs share a time stamp
+            let same_run = old
+                .iter()
+                .position(|o| {
+                    o.test_time_stamp == measurement.test_time_stamp
+                        && o.sheet_name == measurement.sheet_name
+                })
+                .or_else(|| {
+                    old.iter()
+                        .position(|o| o.test_time_stamp == measurement.test_time_stamp)
+                });
+            match same_run {
+                Some(index) => {
+                    let old = old.remove(index);
+                    measurement.id = old.id;
+                    measurement.alias = old.alias;
+                    updated += 1;
+                }
+                None => {
+                    measurement.id = unique_id(&mut taken, &measurement);
+                    measurement.alias = self.generate_alias(measurement.test_time_stamp);
+                    added += 1;
+                }
+            }
+            self.unsaved.insert(measurement.id.clone(), test_data);
+            self.changed.insert(measurement.id.clone());
+            self.measurements.push(measurement);
+        }
+        let removed = old.len();
+        self.discarded.extend(old.into_iter().map(|old| old.id));
+        self.revision = next_revision();
+        (added, updated, removed)
+    }
+
+    pub fn file(&self, path: &str) -> Option<&ScannedFile> {
+        self.files.iter().find(|file| file.path == path)
+    }
+
+    //records the current content of a file that is in the library
+    pub fn scanned(&mut self, path: &str, fingerprint: Fingerprint) {
+        match self.files.iter_mut().find(|file| file.path == path) {
+            Some(file) => {
+                file.fingerprint = Some(fingerprint);
+                file.vanished = false;
+            }
+            None => self.files.push(ScannedFile {
+                path: path.to_string(),
+                fingerprint: Some(fingerprint),
+                vanished: false,
+            }),
+        }
+    }
+
+    //files that were not seen during the scan are flagged, or removed with their measurements
+    pub fn vanished(&mut self, seen: &HashSet<&str>, purge: bool) -> Vec<FileReport> {
+        let gone: Vec<String> = self
+            .files
+            .iter()
+            .filter(|file| !seen.contains(file.path.as_str()))
+            .map(|file| file.path.clone())
+            .collect();
+        if gone.is_empty() {
+            return vec![];
+        }
+        let mut measurements: HashMap<&str, usize> =
+            gone.iter().map(|path| (path.as_str(), 0)).collect();
+        for measurement in self.measurements.iter() {
+            if let Some(count) = measurements.get_mut(measurement.file_path.as_str()) {
+                *count += 1;
+            }
+        }
+        let report = gone
+            .iter()
+            .map(|path| FileReport::vanished(path.as_str(), measurements[path.as_str()], purge))
+            .collect();
+        let gone: HashSet<String> = gone.into_iter().collect();
+        if purge {
+            let (removed, kept): (Vec<_>, Vec<_>) = self
+                .measurements
+                .drain(..)
+                .partition(|measurement| gone.contains(&measurement.file_path));
+            self.measurements = kept;
+            self.discarded
+                .extend(removed.into_iter().map(|measurement| measurement.id));
+            self.files.retain(|file| !gone.contains(&file.path));
+            self.revision = next_revision();
+        } else {
+            for file in self.files.iter_mut() {
+                if gone.contains(&file.path) {
+                    file.vanished = true;
+                }
+            }
+        }
+        report
+    }
+
+    //renames stored processes to their registry name, e.g. after an alias was added to the config
+    pub fn resolve_processes(&mut self, processes: &ProcessRegistry) {
+        for measurement in self.measurements.iter_mut() {
+            if let Some(process) = measurement.device.wafer.as_mut() {
+                let resolved = processes.resolve(&process.0);
+                if *process != resolved {
+                    *process = resolved;
+                    self.changed.insert(measurement.id.clone());
+                    self.revision = next_revision();
+                }
+            }
+        }
+    }
+
+    fn ids(&self) -> HashSet<String> {
+        self.measurements.iter().map(|m| m.id.clone()).collect()
+    }
+
+    pub fn generate_alias(&mut self, time_stamp: TimeStamp) -> String {
+        let string = format!(
+            "{:0>4}{:0>2}{:0>2}",
+            time_stamp.year, time_stamp.month, time_stamp.day
+        );
+        let counter = self.id_day_counter.entry(string.clone()).or_insert(0);
+        *counter += 1;
+
+        format!("{}-{}", string.as_str(), counter)
+    }
+}
+
+//two runs of one sheet with the same time stamp get a counter appended, the id is added to `taken`
+fn unique_id(taken: &mut HashSet<String>, measurement: &MeasurementCompact) -> String {
+    let stable = measurement.stable_id();
+    let mut id = stable.clone();
+    let mut n = 1;
+    while taken.contains(&id) {
+        n += 1;
+        id = format!("{}-{}", stable, n);
+    }
+    taken.insert(id.clone());
+    id
+}
@@ -0,0 +1,186 @@
+use super::Database;
+use crate::elm::filter::FilterQuery;
+use crate::measurement::MeasurementCompact;
+use crate::query::Query;
+use serde::{Deserialize, Serialize};
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+//a selection kept under a name, it is evaluated again whenever it is opened
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "kind", content = "query")]
+pub enum SavedQuery {
+    //the filters of the filter page
+    Filter(Box<FilterQuery>),
+    //in the query language, e.g. "wafer = GF22 and W >= 1um"
+    Text(String),
+}
+
+//named selections of the user, kept with the library
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct Saved {
+    #[serde(default)]
+    pub queries: BTreeMap<String, SavedQuery>,
+    //frozen lists of run ids, runs that were removed since stay listed
+    #[serde(default)]
+    pub collections: BTreeMap<String, Vec<String>>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+pub enum Kind {
+    Query,
+    Collection,
+}
+
+impl Kind {
+    fn name(self) -> &'static str {
+        match self {
+            Kind::Query => "query",
+            Kind::Collection => "collection",
+        }
+    }
+}
+
+impl Saved {
+    fn contains(&self, kind: Kind, name: &str) -> bool {
+        match kind {
+            Kind::Query => self.queries.contains_key(name),
+            Kind::Collection => self.collections.contains_key(name),
+        }
+    }
+
+    //a name that is not blank and not taken by another of the same kind
+    fn free(&self, kind: Kind, name: &str) -> Result<String, String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(format!("A {} needs a name", kind.name()));
+        }
+        if self.contains(kind, name) {
+            return Err(format!("There is a {} named {} already", kind.name(), name));
+        }
+        Ok(name.to_string())
+    }
+
+    //takes over the entries of another library whose names are free, `ids` gives the id every
+    //merged run of the other library has in this one, ids of runs that were not merged are left
+    //out of the collections, returns the entries left out with the reason and how many ids were
+    //left out
+    pub fn merge(&mut self, other: Saved, ids: &HashMap<String, String>) -> (Vec<String>, usize) {
+        let mut left_out = vec![];
+        for (name, query) in other.queries {
+            match self.queries.entry(name) {
+                Entry::Occupied(taken) => {
+                    left_out.push(format!("query {}: the name is taken", taken.key()))
+                }
+                Entry::Vacant(free) => {
+                    free.insert(query);
+                }
+            }
+        }
+        let mut runs_left_out = 0;
+        for (name, collection) in other.collections {
+            if self.collections.contains_key(&name) {
+                left_out.push(format!("collection {}: the name is taken", name));
+                continue;
+            }
+            let amount = collection.len();
+            let mut seen = HashSet::new();
+            let collection: Vec<String> = collection
+                .iter()
+                .filter_map(|id| ids.get(id))
+                .filter(|id| seen.insert(id.as_str()))
+                .cloned()
+                .collect();
+            runs_left_out += amount - collection.len();
+            if collection.is_empty() {
+                left_out.push(format!("collection {}: none of its runs were merged", name));
+            } else {
+                self.collections.insert(name, collection);
+            }
+        }
+        (left_out, runs_left_out)
+    }
+}
+
+impl Database {
+    //a text query is only kept when it can be read
+    pub fn save_query(&mut self, name: &str, query: SavedQuery) -> Result<(), String> {
+        let name = self.saved.free(Kind::Query, name)?;
+        if let SavedQuery::Text(text) = &query {
+            Query::parse(text.as_str()).map_err(|e| e.show(text.as_str()))?;
+        }
+        self.saved.queries.insert(name, query);
+        Ok(())
+    }
+
+    //the runs are given by their ids or aliases, each is kept once by its id
+    pub fn save_collection(&mut self, name: &str, runs: &[String]) -> Result<(), String> {
+        let name = self.saved.free(Kind::Collection, name)?;
+        let mut known: HashMap<&str, &str> = HashMap::new();
+        for measurement in self.measurements.iter() {
+            known.insert(measurement.alias.as_str(), measurement.id.as_str());
+            known.insert(measurement.id.as_str(), measurement.id.as_str());
+        }
+        let mut ids: Vec<String> = vec![];
+        let mut seen = HashSet::new();
+        for run in runs {
+            let id = match known.get(run.as_str()) {
+                Some(id) => id.to_string(),
+                None => self
+                    .resolve_id(run)
+                    .ok_or_else(|| format!("There is no run {}", run))?,
+            };
+            if seen.insert(id.clone()) {
+                ids.push(id);
+            }
+        }
+        self.saved.collections.insert(name, ids);
+        Ok(())
+    }
+
+    pub fn rename_saved(&mut self, kind: Kind, name: &str, to: &str) -> Result<(), String> {
+        if !self.saved.contains(kind, name) {
+            return Err(format!("There is no {} named {}", kind.name(), name));
+        }
+        let to = self.saved.free(kind, to)?;
+        match kind {
+            Kind::Query => {
+                let query = self.saved.queries.remove(name).unwrap();
+                self.saved.queries.insert(to, query);
+            }
+            Kind::Collection => {
+                let collection = self.saved.collections.remove(name).unwrap();
+                self.saved.collections.insert(to, collection);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn delete_saved(&mut self, kind: Kind, name: &str) -> Result<(), String> {
+        let removed = match kind {
+            Kind::Query => self.saved.queries.remove(name).is_some(),
+            Kind::Collection => self.saved.collections.remove(name).is_some(),
+        };
+        if removed {
+            Ok(())
+        } else {
+            Err(format!("There is no {} named {}", kind.name(), name))
+        }
+    }
+
+    //the runs of a collection that are still in the library, in the order they were collected
+    pub fn collection(&self, name: &str) -> Option<Vec<MeasurementCompact>> {
+        let ids = self.saved.collections.get(name)?;
+        let runs: HashMap<&str, &MeasurementCompact> = self
+            .measurements
+            .iter()
+            .map(|measurement| (measurement.id.as_str(), measurement))
+            .collect();
+        Some(
+            ids.iter()
+                .filter_map(|id| runs.get(id.as_str()))
+                .map(|run| (*run).clone())
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,908 @@
+use super::filter::{exact_keys, FilterOptions, FilterQuery};
+use super::range::{self, exact_key, Quantity, QUANTITIES};
+use super::terminal::{self, Property, TerminalOptions};
+use crate::measurement::terminal_parameter::terminal::Terminal;
+use crate::measurement::timestamp::Date;
+use crate::measurement::MeasurementCompact;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+//runs by their position in the measurements the index was built from
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bitset {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl Bitset {
+    pub fn empty(len: usize) -> Bitset {
+        Bitset {
+            words: vec![0; len.div_ceil(64)],
+            len,
+        }
+    }
+
+    pub fn full(len: usize) -> Bitset {
+        let mut bitset = Bitset {
+            words: vec![u64::MAX; len.div_ceil(64)],
+            len,
+        };
+        if !len.is_multiple_of(64) {
+            if let Some(last) = bitset.words.last_mut() {
+                *last = (1 << (len % 64)) - 1;
+            }
+        }
+        bitset
+    }
+
+    pub fn insert(&mut self, run: usize) {
+        self.words[run / 64] |= 1 << (run % 64);
+    }
+
+    pub fn and(&mut self, other: &Bitset) {
+        for (word, other) in self.words.iter_mut().zip(other.words.iter()) {
+            *word &= other;
+        }
+    }
+
+    pub fn or(&mut self, other: &Bitset) {
+        for (word, other) in self.words.iter_mut().zip(other.words.iter()) {
+            *word |= other;
+        }
+    }
+
+    //the number of runs in both, without building the intersection
+    pub fn count_and(&self, other: &Bitset) -> u32 {
+        self.words
+            .iter()
+            .zip(other.words.iter())
+            .map(|(word, other)| (word & other).count_ones())
+            .sum()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(|(n, word)| {
+                (0..64)
+                    .filter(move |bit| word & (1 << bit) != 0)
+                    .map(move |bit| n * 64 + bit)
+            })
+            .filter(move |run| *run < self.len)
+    }
+}
+
+//the distinct keys of a facet and the runs with each key
+#[derive(Debug, Default)]
+struct Keys {
+    names: Vec<String>,
+    runs: Vec<Bitset>,
+    positions: HashMap<String, usize>,
+}
+
+impl Keys {
+    fn add(&mut self, name: &str, run: usize, len: usize) {
+        let position = match self.positions.get(name) {
+            Some(position) => *position,
+            None => {
+                self.names.push(name.to_string());
+                self.runs.push(Bitset::empty(len));
+                self.positions
+                    .insert(name.to_string(), self.names.len() - 1);
+                self.names.len() - 1
+            }
+        };
+        self.runs[position].insert(run);
+    }
+
+    fn get(&self, name: &str) -> Option<&Bitset> {
+        self.positions
+            .get(name)
+            .map(|position| &self.runs[*position])
+    }
+
+    //the runs with one of the keys
+    fn select<'a, I: IntoIterator<Item = &'a String>>(&self, names: I, len: usize) -> Bitset {
+        let mut selected = Bitset::empty(len);
+        for runs in names.into_iter().filter_map(|name| self.get(name)) {
+            selected.or(runs);
+        }
+        selected
+    }
+
+    //runs per key among `within`, keys without runs are left out unless `keep_empty`
+    fn counts(&self, within: &Bitset, keep_empty: bool) -> HashMap<String, u32> {
+        self.names
+            .iter()
+            .zip(self.runs.iter())
+            .map(|(name, runs)| (name.clone(), runs.count_and(within)))
+            .filter(|(_, count)| keep_empty || *count > 0)
+            .collect()
+    }
+}
+
+//the distinct numbers of a terminal property and the runs with each number
+#[derive(Debug, Default)]
+struct Numbers {
+    numbers: Vec<f64>,
+    runs: Vec<Bitset>,
+    positions: HashMap<u64, usize>,
+}
+
+impl Numbers {
+    fn add(&mut self, number: f64, run: usize, len: usize) {
+        let position = match self.positions.get(&number.to_bits()) {
+            Some(position) => *position,
+            None => {
+                self.numbers.push(number);
+                self.runs.push(Bitset::empty(len));
+                self.positions
+                    .insert(number.to_bits(), self.numbers.len() - 1);
+                self.numbers.len() - 1
+            }
+        };
+        self.runs[position].insert(run);
+    }
+
+    //the runs with a number from `min` to `max`, each number is compared once
+    fn within(&self, min: Option<f64>, max: Option<f64>, len: usize) -> Bitset {
+        let mut selected = Bitset::empty(len);
+        for (_, runs) in self
+            .numbers
+            .iter()
+            .zip(self.runs.iter())
+            .filter(|(number, _)| range::within(**number, min, max, false))
+        {
+            selected.or(runs);
+        }
+        selected
+    }
+}
+
+//names of numbers, formatted once per distinct value
+#[derive(Default)]
+struct Names<K> {
+    names: HashMap<K, String>,
+}
+
+impl<K: std::hash::Hash + Eq + Copy> Names<K> {
+    fn of(&mut self, key: K, name: impl Fn(K) -> String) -> &str {
+        self.names.entry(key).or_insert_with(|| name(key))
+    }
+}
+
+//what FilterQuery selects by, a facet is counted with the runs that pass all others
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Constraint {
+    SheetNames,
+    Widths,
+    Lengths,
+    Temps,
+    Processes,
+    Dies,
+    TestTypes,
+    Speeds,
+    Dates,
+    Settings,
+    Range(Quantity),
+    Terminal(Terminal, Property),
+}
+
+//the facets of a list of runs, built in one pass, FilterOptions of any query are
+//combinations of its bitsets
+pub struct FacetIndex {
+    len: usize,
+    sheet_names: Keys,
+    //offered with 0 for a missing width or length, selected with 0.001 as FilterQuery compares
+    widths: Keys,
+    width_matches: Keys,
+    lengths: Keys,
+    length_matches: Keys,
+    temps: Keys,
+    processes: Keys,
+    dies: Keys,
+    test_types: Keys,
+    speeds: Keys,
+    dates: Keys,
+    //yyyymmdd per run
+    days: Vec<u32>,
+    values: BTreeMap<Quantity, Vec<Option<f64>>>,
+    terminals: BTreeMap<(Terminal, Property), Keys>,
+    terminal_numbers: BTreeMap<(Terminal, Property), Numbers>,
+}
+
+impl FacetIndex {
+    pub fn new(measurements: &[MeasurementCompact]) -> FacetIndex {
+        let len = measurements.len();
+        let mut index = FacetIndex {
+            len,
+            sheet_names: Keys::default(),
+            widths: Keys::default(),
+            width_matches: Keys::default(),
+            lengths: Keys::default(),
+            length_matches: Keys::default(),
+            temps: Keys::default(),
+            processes: Keys::default(),
+            dies: Keys::default(),
+            test_types: Keys::default(),
+            speeds: Keys::default(),
+            dates: Keys::default(),
+            days: Vec::with_capacity(len),
+            values: QUANTITIES
+                .iter()
+                .map(|quantity| (*quantity, Vec::with_capacity(len)))
+                .collect(),
+            terminals: BTreeMap::new(),
+            terminal_numbers: BTreeMap::new(),
+        };
+        let mut numbers: Names<u64> = Names::default();
+        let mut temps: Names<u32> = Names::default();
+        let mut dates: Names<u32> = Names::default();
+        for (run, measurement) in measurements.iter().enumerate() {
+            let device = &measurement.device;
+            let parameter = &measurement.test_parameter;
+            let time_stamp = measurement.test_time_stamp;
+            let exact = |bits: u64| exact_key(f64::from_bits(bits));
+            index.sheet_names.add(&measurement.sheet_name, run, len);
+            for (offered, matched, value) in [
+                (&mut index.widths, &mut index.width_matches, device.width),
+                (&mut index.lengths, &mut index.length_matches, device.length),
+            ] {
+                offered.add(numbers.of(value.unwrap_or(0.0).to_bits(), exact), run, len);
+                matched.add(
+                    numbers.of(value.unwrap_or(0.001).to_bits(), exact),
+                    run,
+                    len,
+                );
+            }
+            let temp = temps.of(device.temperature.unwrap_or(0), |t| format!("{}K", t));
+            index.temps.add(temp, run, len);
+            let process = device
+                .wafer
+                .as_ref()
+                .map(|process| process.to_string())
+                .unwrap_or_default();
+            index.processes.add(&process, run, len);
+            index
+                .dies
+                .add(device.die.as_deref().unwrap_or_default(), run, len);
+            index
+                .test_types
+                .add(&parameter.test_type.to_string(), run, len);
+            index
+                .speeds
+                .add(&parameter.measurement_speed.to_string(), run, len);
+            let day = time_stamp.year as u32 * 10000
+                + time_stamp.month as u32 * 100
+                + time_stamp.day as u32;
+            let date = dates.of(day, |_| Date::from(time_stamp).to_string());
+            index.dates.add(date, run, len);
+            index.days.push(day);
+            for (quantity, values) in index.values.iter_mut() {
+                values.push(quantity.of(measurement));
+            }
+            for parameter in measurement.terminals.iter() {
+                for (property, value, number) in terminal::values_of(parameter) {
+                    index
+                        .terminals
+                        .entry((parameter.terminal, property))
+                        .or_default()
+                        .add(&value, run, len);
+                    if let Some(number) = number {
+                        index
+                            .terminal_numbers
+                            .entry((parameter.terminal, property))
+                            .or_default()
+                            .add(number, run, len);
+                    }
+                }
+            }
+        }
+        index
+    }
+
+    //the runs `query` keeps
+    pub fn select(&self, measurements: &[MeasurementCompact], query: &FilterQuery) -> Bitset {
+        let mut selected = Bitset::full(self.len);
+        for (_, passing, _) in self.constraints(measurements, query) {
+            selected.and(&passing);
+        }
+        selected
+    }
+
+    //the runs passing each constraint of the query, and for widths and lengths the runs a
+    //selection of all offered values keeps
+    fn constraints(
+        &self,
+        measurements: &[MeasurementCompact],
+        query: &FilterQuery,
+    ) -> Vec<(Constraint, Bitset, Option<Bitset>)> {
+        let len = self.len;
+        let temps: Vec<String> = query
+            .temps
+            .iter()
+            .map(|temp| {
+                let digits: String = temp.chars().filter(|c| c.is_ascii_digit()).collect();
+                format!("{}K", digits)
+            })
+            .collect();
+        let mut constraints = vec![
+            (
+                Constraint::SheetNames,
+                self.sheet_names.select(&query.sheet_names, len),
+                None,
+            ),
+            (
+                Constraint::Widths,
+                self.width_matches.select(&exact_keys(&query.widths), len),
+                Some(self.width_matches.select(&self.widths.names, len)),
+            ),
+            (
+                Constraint::Lengths,
+                self.length_matches.select(&exact_keys(&query.lengths), len),
+                Some(self.length_matches.select(&self.lengths.names, len)),
+            ),
+            (Constraint::Temps, self.temps.select(&temps, len), None),
+            (
+                Constraint::Processes,
+                self.processes.select(Some(&query.wafer), len),
+                None,
+            ),
+            (Constraint::Dies, self.dies.select(&query.dies, len), None),
+            (
+                Constraint::TestTypes,
+                self.test_types.select(Some(&query.test_type), len),
+                None,
+            ),
+            (
+                Constraint::Speeds,
+                self.speeds.select(&query.measurement_speeds, len),
+                None,
+            ),
+        ];
+        let day = |date: Option<Date>, otherwise: u32| {
+            date.map_or(otherwise, |date| {
+                date.year as u32 * 10000 + date.month as u32 * 100 + date.day as u32
+            })
+        };
+        let (from, to) = (
+            day(query.dates_between.0, u32::MIN),
+            day(query.dates_between.1, u32::MAX),
+        );
+        constraints.push((
+            Constraint::Dates,
+            self.passing(|run| (from..=to).contains(&self.days[run])),
+            None,
+        ));
+        if !query.settings.is_empty() {
+            constraints.push((
+                Constraint::Settings,
+                self.passing(|run| {
+                    let measurement = &measurements[run];
+                    query.settings.iter().all(|setting| {
+                        measurement
+                            .settings
+                            .value(setting.terminal.as_deref(), setting.label.as_str())
+                            .is_some_and(|found| {
+                                super::filter::same_setting(found, setting.value.as_str())
+                            })
+                    })
+                }),
+                None,
+            ));
+        }
+        let quantities: Vec<Quantity> = QUANTITIES
+            .iter()
+            .copied()
+            .filter(|quantity| query.ranges.iter().any(|range| range.quantity == *quantity))
+            .collect();
+        for quantity in quantities {
+            let values = &self.values[&quantity];
+            constraints.push((
+                Constraint::Range(quantity),
+                self.passing(|run| {
+                    query
+                        .ranges
+                        .iter()
+                        .filter(|range| range.quantity == quantity)
+                        .all(|range| values[run].is_some_and(|value| range.contains(value)))
+                }),
+                None,
+            ));
+        }
+        let mut pairs: Vec<(Terminal, Property)> = vec![];
+        for filter in query.terminals.iter() {
+            if !pairs.contains(&(filter.terminal, filter.property)) {
+                pairs.push((filter.terminal, filter.property));
+            }
+        }
+        for (terminal, property) in pairs {
+            let mut passing = Bitset::full(len);
+            for filter in query
+                .terminals
+                .iter()
+                .filter(|filter| filter.terminal == terminal && filter.property == property)
+            {
+                //values alone are looked up, as are numbers in a range, both together are
+                //compared per run
+                let runs = if filter.min.is_none() && filter.max.is_none() {
+                    let keys: HashSet<String> =
+                        filter.keys().iter().map(|key| key.to_lowercase()).collect();
+                    let empty = Keys::default();
+                    let values = self.terminals.get(&(terminal, property)).unwrap_or(&empty);
+                    values.select(
+                        values
+                            .names
+                            .iter()
+                            .filter(|name| keys.is_empty() || keys.contains(&name.to_lowercase())),
+                        len,
+                    )
+                } else if filter.values.is_empty() {
+                    self.terminal_numbers
+                        .get(&(terminal, property))
+                        .map_or(Bitset::empty(len), |numbers| {
+                            numbers.within(filter.min, filter.max, len)
+                        })
+                } else {
+                    self.passing(|run| filter.matches(&measurements[run]))
+                };
+                passing.and(&runs);
+            }
+            constraints.push((Constraint::Terminal(terminal, property), passing, None));
+        }
+        constraints
+    }
+
+    fn passing<F: Fn(usize) -> bool>(&self, keep: F) -> Bitset {
+        let mut passing = Bitset::empty(self.len);
+        for run in (0..self.len).filter(|run| keep(*run)) {
+            passing.insert(run);
+        }
+        passing
+    }
+
+    //the counts of all values of all runs
+    pub fn all(&self) -> FilterOptions {
+        let all = Bitset::full(self.len);
+        FilterOptions {
+            sheet_names: self.sheet_names.counts(&all, false),
+            widths: self.widths.counts(&all, false),
+            lengths: self.lengths.counts(&all, false),
+            temps: self.temps.counts(&all, false),
+            processes: self.processes.counts(&all, false),
+            dies: self.dies.counts(&all, false),
+            test_types: self.test_types.counts(&all, false),
+            measurement_speeds: self.speeds.counts(&all, false),
+            dates: self.dates.counts(&all, false),
+            bins: QUANTITIES
+                .iter()
+                .map(|quantity| (*quantity, self.bins(*quantity, &all)))
+                .collect(),
+            terminals: self.terminal_counts(&all),
+        }
+    }
+
+    //the counts of each facet among the runs that pass the other constraints of the query
+    pub fn options(
+        &self,
+        measurements: &[MeasurementCompact],
+        query: &FilterQuery,
+    ) -> FilterOptions {
+        let constraints = self.constraints(measurements, query);
+        //others[n] holds the runs that pass all constraints but the n-th
+        let mut others = vec![Bitset::full(self.len); constraints.len()];
+        let mut before = Bitset::full(self.len);
+        for (n, (_, passing, _)) in constraints.iter().enumerate() {
+            others[n].and(&before);
+            before.and(passing);
+        }
+        let mut after = Bitset::full(self.len);
+        for (n, (_, passing, relaxed)) in constraints.iter().enumerate().rev() {
+            others[n].and(&after);
+            if let Some(relaxed) = relaxed {
+                others[n].and(relaxed);
+            }
+            after.and(passing);
+        }
+        let selected = after;
+        let within = |constraint: Constraint| {
+            constraints
+                .iter()
+                .position(|(other, _, _)| *other == constraint)
+                .map_or(&selected, |n| &others[n])
+        };
+        let mut terminals = self.terminal_counts(&selected);
+        for (n, (constraint, _, _)) in constraints.iter().enumerate() {
+            if let Constraint::Terminal(terminal, property) = constraint {
+                let counts = self
+                    .terminals
+                    .get(&(*terminal, *property))
+                    .map(|values| values.counts(&others[n], false))
+                    .unwrap_or_default();
+                terminals
+                    .entry(*terminal)
+                    .or_default()
+                    .insert(*property, counts);
+            }
+        }
+        FilterOptions {
+            sheet_names: self
+                .sheet_names
+                .counts(within(Constraint::SheetNames), false),
+            widths: self.widths.counts(within(Constraint::Widths), false),
+            lengths: self.lengths.counts(within(Constraint::Lengths), false),
+            temps: self.temps.counts(within(Constraint::Temps), false),
+            processes: self.processes.counts(within(Constraint::Processes), true),
+            dies: self.dies.counts(within(Constraint::Dies), false),
+            test_types: self.test_types.counts(within(Constraint::TestTypes), true),
+            measurement_speeds: self.speeds.counts(within(Constraint::Speeds), false),
+            dates: self.dates.counts(within(Constraint::Dates), false),
+            bins: QUANTITIES
+                .iter()
+                .map(|quantity| {
+                    (
+                        *quantity,
+                        self.bins(*quantity, within(Constraint::Range(*quantity))),
+                    )
+                })
+                .collect(),
+            terminals,
+        }
+    }
+
+    fn bins(&self, quantity: Quantity, within: &Bitset) -> Vec<range::Bin> {
+        let values = &self.values[&quantity];
+        range::bins(quantity, within.iter().filter_map(|run| values[run]))
+    }
+
+    fn terminal_counts(&self, within: &Bitset) -> TerminalOptions {
+        let mut options = TerminalOptions::new();
+        for ((terminal, property), values) in self.terminals.iter() {
+            let counts = values.counts(within, false);
+            if !counts.is_empty() {
+                options
+                    .entry(*terminal)
+                    .or_default()
+                    .insert(*property, counts);
+            }
+        }
+        options
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::elm::range::RangeFilter;
+    use crate::elm::terminal::TerminalFilter;
+    use serde_json::json;
+    use std::time::{Duration, Instant};
+
+    const RUNS: usize = 100_000;
+
+    //the counts of 100k runs take up to about 100 ms in a debug build and 20 ms in a release
+    //build, the budget leaves room for a slow machine but not for a pass over the runs per facet
+    fn budget() -> Duration {
+        Duration::from_millis(if cfg!(debug_assertions) { 500 } else { 100 })
+    }
+
+    //a run with the sheet, device, date, speed and terminals varied by n
+    pub(crate) fn run(n: usize) -> MeasurementCompact {
+        let sampling = n.is_multiple_of(3);
+        let gate = if sampling {
+            json!({"op_type": "VoltageBias", "bias": 0.3 + 0.1 * (n % 4) as f64,
+                   "start": null, "stop": null, "stepsize": null})
+        } else {
+            json!({"op_type": "VoltageLinearSweep", "bias": null, "start": 0.0,
+                   "stop": ([1.5, 3.0][n % 2]), "stepsize": ([0.01, 0.5][n % 2])})
+        };
+        let current_range = if n.is_multiple_of(11) {
+            json!({"LimitedAuto": "1nA"})
+        } else {
+            json!("Auto")
+        };
+        serde_json::from_value(json!({
+            "id": format!("20200101-{:08x}", n),
+            "file_path": format!("D{}/runs#{}.xls", n % 20, n / 50),
+            "sheet_name": format!("Run{}", n % 50),
+            "device": {
+                "wafer": ([Some("GF22"), Some("MINOXG"), None][n % 7 % 3]),
+                "die": if n.is_multiple_of(97) { None } else { Some(format!("D{}", n % 20)) },
+                "temperature": 4 + (n % 30) * 10,
+                "width": if n.is_multiple_of(89) { None } else { Some(250.0 * (1 + n % 40) as f64) },
+                "length": 100.0 * (1 + n % 25) as f64,
+                "extra": {}
+            },
+            "test_parameter": {
+                "test_type": if sampling { "Sampling" } else { "Sweeping" },
+                "measurement_speed": (["Fast", "Normal", "Quiet"][n / 3 % 3]),
+                "ad_aperture": null, "filter_factor": null,
+                "interval_time": if sampling { Some(0.01) } else { None },
+                "sweep_delay_time": null, "hold_time": 0.0
+            },
+            "test_time_stamp": {"year": 2019 + n / 11 % 3, "month": 1 + n % 12, "day": 1 + n % 28,
+                                "hour": 12, "minute": 0, "second": 0},
+            "terminals": [
+                {"terminal": "Drain", "instrument": (["SMU1", "SMU3"][n % 2]),
+                 "operational_mode": {"op_type": "VoltageBias", "bias": 0.05 * (1 + n % 4) as f64,
+                                      "start": null, "stop": null, "stepsize": null},
+                 "compliance": 0.1, "voltage": "Programmed", "voltage_range": "BestFixed",
+                 "current": "Measured", "current_range": current_range},
+                {"terminal": "Gate", "instrument": (["SMU2", "SMU4"][n % 2]), "operational_mode": gate,
+                 "compliance": 0.1, "voltage": "Programmed", "voltage_range": "BestFixed",
+                 "current": null, "current_range": "Auto"},
+                {"terminal": "Source", "instrument": "GNDU",
+                 "operational_mode": {"op_type": "Common", "bias": null, "start": null,
+                                      "stop": null, "stepsize": null},
+                 "compliance": null, "voltage": null, "voltage_range": null, "current": null,
+                 "current_range": null}
+            ],
+            "test_data": []
+        }))
+        .unwrap()
+    }
+
+    //the runs FilterQuery::filter keeps, for each of `wafers` and `test_types`
+    fn kept(
+        runs: &[MeasurementCompact],
+        query: &FilterQuery,
+        wafers: &[String],
+        test_types: &[String],
+    ) -> Vec<MeasurementCompact> {
+        let mut kept = vec![];
+        for wafer in wafers {
+            for test_type in test_types {
+                let query = FilterQuery {
+                    wafer: wafer.clone(),
+                    test_type: test_type.clone(),
+                    ..query.clone()
+                };
+                kept.extend(query.filter(runs.to_vec()));
+            }
+        }
+        kept
+    }
+
+    fn nonzero(counts: &HashMap<String, u32>) -> HashMap<String, u32> {
+        counts
+            .iter()
+            .filter(|(_, count)| **count > 0)
+            .map(|(key, count)| (key.clone(), *count))
+            .collect()
+    }
+
+    //every facet of `options` counted again over the runs FilterQuery::filter keeps when that
+    //facet alone is relaxed to all values
+    pub(crate) fn check(
+        runs: &[MeasurementCompact],
+        all: &FilterOptions,
+        query: &FilterQuery,
+        options: &FilterOptions,
+    ) {
+        let every = FilterQuery::from(all.clone());
+        let wafer = [query.wafer.clone()];
+        let test_type = [query.test_type.clone()];
+        let wafers: Vec<String> = all.processes.keys().cloned().collect();
+        let test_types: Vec<String> = all.test_types.keys().cloned().collect();
+        let counted = |relaxed: FilterQuery, wafers: &[String], test_types: &[String]| {
+            FacetIndex::new(&kept(runs, &relaxed, wafers, test_types)).all()
+        };
+        let selected = counted(query.clone(), &wafer, &test_type);
+        let relax = |change: &dyn Fn(&mut FilterQuery)| {
+            let mut relaxed = query.clone();
+            change(&mut relaxed);
+            counted(relaxed, &wafer, &test_type)
+        };
+        let facets = [
+            (
+                "sheet names",
+                &options.sheet_names,
+                relax(&|q| q.sheet_names = every.sheet_names.clone()).sheet_names,
+            ),
+            (
+                "widths",
+                &options.widths,
+                relax(&|q| q.widths = every.widths.clone()).widths,
+            ),
+            (
+                "lengths",
+                &options.lengths,
+                relax(&|q| q.lengths = every.lengths.clone()).lengths,
+            ),
+            (
+                "temps",
+                &options.temps,
+                relax(&|q| q.temps = every.temps.clone()).temps,
+            ),
+            (
+                "dies",
+                &options.dies,
+                relax(&|q| q.dies = every.dies.clone()).dies,
+            ),
+            (
+                "speeds",
+                &options.measurement_speeds,
+                relax(&|q| q.measurement_speeds = every.measurement_speeds.clone())
+                    .measurement_speeds,
+            ),
+            (
+                "dates",
+                &options.dates,
+                relax(&|q| q.dates_between = (None, None)).dates,
+            ),
+            (
+                "processes",
+                &options.processes,
+                counted(query.clone(), &wafers, &test_type).processes,
+            ),
+            (
+                "test types",
+                &options.test_types,
+                counted(query.clone(), &wafer, &test_types).test_types,
+            ),
+        ];
+        for (facet, options, expected) in facets.iter() {
+            assert_eq!(&nonzero(options), expected, "{} of {:?}", facet, query);
+        }
+        for (quantity, bins) in options.bins.iter() {
+            let expected = if query.ranges.iter().any(|range| range.quantity == *quantity) {
+                relax(&|q| q.ranges.retain(|range| range.quantity != *quantity)).bins
+            } else {
+                selected.bins.clone()
+            };
+            let expected = expected.get(quantity).cloned().unwrap_or_default();
+            assert_eq!(
+                serde_json::to_value(bins).unwrap(),
+                serde_json::to_value(expected).unwrap(),
+                "{:?} bins of {:?}",
+                quantity,
+                query
+            );
+        }
+        for (terminal, properties) in options.terminals.iter() {
+            for (property, counts) in properties.iter() {
+                let filtered = query
+                    .terminals
+                    .iter()
+                    .any(|filter| filter.terminal == *terminal && filter.property == *property);
+                let within = if filtered {
+                    relax(&|q| {
+                        q.terminals.retain(|filter| {
+                            filter.terminal != *terminal || filter.property != *property
+                        })
+                    })
+                } else {
+                    selected.clone()
+                };
+                let expected = within
+                    .terminals
+                    .get(terminal)
+                    .and_then(|properties| properties.get(property))
+                    .cloned()
+                    .unwrap_or_default();
+                assert_eq!(
+                    counts, &expected,
+                    "{:?} {:?} of {:?}",
+                    terminal, property, query
+                );
+            }
+        }
+    }
+
+    //all values, fewer values, a range with dates and terminal filters, of either test type
+    pub(crate) fn queries(all: &FilterOptions) -> Vec<FilterQuery> {
+        //sorted so the narrowed queries keep the same values each time
+        let mut everything = FilterQuery::from(all.clone());
+        everything.wafer = String::from("GF22");
+        everything.widths.sort();
+        everything.dies.sort();
+        everything.temps.sort();
+        everything.sheet_names.sort();
+        let mut narrowed = everything.clone();
+        narrowed.widths.truncate(10);
+        narrowed.dies.truncate(5);
+        narrowed.sheet_names.truncate(20);
+        let mut ranged = everything.clone();
+        ranged.ranges.push(RangeFilter {
+            quantity: Quantity::Ratio,
+            min: Some(1.0),
+            max: None,
+            below_max: false,
+        });
+        ranged.temps.truncate(8);
+        ranged.dates_between = (
+            Some(Date {
+                year: 2020,
+                month: 1,
+                day: 1,
+            }),
+            None,
+        );
+        let mut terminals = everything.clone();
+        terminals.terminals = serde_json::from_value::<Vec<TerminalFilter>>(json!([
+            {"terminal": "Drain", "property": "Bias", "min": 0.1, "max": 0.15},
+            {"terminal": "Gate", "property": "Instrument", "values": ["SMU2"]},
+            {"terminal": "Drain", "property": "CurrentRange", "values": ["Auto"]}
+        ]))
+        .unwrap();
+        let mut queries = vec![];
+        for query in [everything, narrowed, ranged, terminals].iter() {
+            for test_type in ["Sampling", "Sweeping"].iter() {
+                queries.push(FilterQuery {
+                    test_type: test_type.to_string(),
+                    ..query.clone()
+                });
+            }
+        }
+        queries
+    }
+
+    //the runs the index selects are those FilterQuery::filter keeps, in the same order
+    fn check_selection(index: &FacetIndex, runs: &[MeasurementCompact], query: &FilterQuery) {
+        let selected: Vec<&str> = index
+            .select(runs, query)
+            .iter()
+            .map(|run| runs[run].id.as_str())
+            .collect();
+        let expected = query.filter(runs.to_vec());
+        assert!(
+            !expected.is_empty() && expected.len() < runs.len(),
+            "{:?}",
+            query
+        );
+        let expected: Vec<&str> = expected.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(selected, expected, "{:?}", query);
+    }
+
+    #[test]
+    fn options_agree_with_filter() {
+        let runs: Vec<MeasurementCompact> = (0..10_000).map(run).collect();
+        let index = FacetIndex::new(&runs);
+        let all = index.all();
+        for query in queries(&all).iter() {
+            check_selection(&index, &runs, query);
+            check(&runs, &all, query, &index.options(&runs, query));
+        }
+    }
+
+    //counting each facet again with FilterQuery::filter takes minutes for 100k runs in a debug
+    //build, so here only the selection is compared
+    #[test]
+    fn options_of_100k_runs_agree_with_the_selection() {
+        let runs: Vec<MeasurementCompact> = (0..RUNS).map(run).collect();
+        let index = FacetIndex::new(&runs);
+        for query in queries(&index.all()).iter() {
+            let options = index.options(&runs, query);
+            //the runs of the selected test type are those passing all constraints
+            assert_eq!(
+                options.test_types[&query.test_type],
+                index.select(&runs, query).iter().count() as u32
+            );
+            check_selection(&index, &runs, query);
+        }
+    }
+
+    //depends on the machine, run with `cargo test --release -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn options_of_100k_runs_within_budget() {
+        let runs: Vec<MeasurementCompact> = (0..RUNS).map(run).collect();
+        let started = Instant::now();
+        let index = FacetIndex::new(&runs);
+        println!("index of {} runs built in {:?}", RUNS, started.elapsed());
+        let mut slowest = Duration::default();
+        for query in queries(&index.all()).iter() {
+            let started = Instant::now();
+            index.options(&runs, query);
+            let counted = started.elapsed();
+            assert!(
+                counted < budget(),
+                "counting took {:?} for {:?}",
+                counted,
+                query
+            );
+            slowest = slowest.max(counted);
+        }
+        println!("slowest count of {} runs took {:?}", RUNS, slowest);
+    }
+}
@@ -1,40 +1,93 @@
 //neasurement imports
-use crate::measurement::device::process::Process;
-use crate::measurement::testparameter::MeasurementSpeed;
-use crate::measurement::testparameter::TestType;
 use crate::measurement::timestamp::Date;
 use crate::measurement::MeasurementCompact;
 
+use super::facets::FacetIndex;
+use super::range::{exact_key, Bin, Quantity, RangeFilter};
+use super::terminal::{TerminalFilter, TerminalOptions};
 use boolinator::Boolinator;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::iter::FromIterator;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct FilterQuery {
-    sheet_names: Vec<String>,
-    widths: Vec<String>,
-    lengths: Vec<String>,
-    temps: Vec<String>,
-    wafer: String,
-    dies: Vec<String>,
-    test_type: String,
-    measurement_speeds: Vec<String>,
-    dates_between: (Option<Date>, Option<Date>),
+    pub sheet_names: Vec<String>,
+    pub widths: Vec<String>,
+    pub lengths: Vec<String>,
+    pub temps: Vec<String>,
+    pub wafer: String,
+    pub dies: Vec<String>,
+    pub test_type: String,
+    pub measurement_speeds: Vec<String>,
+    pub dates_between: (Option<Date>, Option<Date>),
+    #[serde(default)]
+    pub settings: Vec<SettingFilter>,
+    //W, L, W/L, area or temperature from a minimum to a maximum
+    #[serde(default)]
+    pub ranges: Vec<RangeFilter>,
+    //mode, bias, instrument, ranges, ... of a terminal
+    #[serde(default)]
+    pub terminals: Vec<TerminalFilter>,
+}
+
+//matches a row of the "Settings" sheet, e.g. label "Sweep Delay" with value "1E-3"
+//a terminal name (e.g. "Drain") selects the value of that terminal column instead of a global row
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SettingFilter {
+    pub label: String,
+    #[serde(default)]
+    pub terminal: Option<String>,
+    pub value: String,
+}
+
+impl SettingFilter {
+    fn matches(&self, measurement: &MeasurementCompact) -> bool {
+        let found = match measurement
+            .settings
+            .value(self.terminal.as_deref(), self.label.as_str())
+        {
+            Some(found) => found,
+            None => return false,
+        };
+        same_setting(found, self.value.as_str())
+    }
+}
+
+//numbers are compared by value so "0.001" matches "1E-3"
+pub fn same_setting(found: &str, wanted: &str) -> bool {
+    match (found.parse::<f64>(), wanted.trim().parse::<f64>()) {
+        (Ok(found), Ok(wanted)) => (found - wanted).abs() <= f64::EPSILON * wanted.abs(),
+        _ => found.eq_ignore_ascii_case(wanted.trim()),
+    }
+}
+
+//widths and lengths as they are offered, "250.00000001" is the same as "250"
+pub fn exact_keys(values: &[String]) -> Vec<String> {
+    values
+        .iter()
+        .map(|value| {
+            value
+                .trim()
+                .parse::<f64>()
+                .map(exact_key)
+                .unwrap_or_else(|_| value.clone())
+        })
+        .collect()
 }
 
 impl FilterQuery {
     pub fn filter(&self, measurements: Vec<MeasurementCompact>) -> Vec<MeasurementCompact> {
+        let widths = exact_keys(&self.widths);
+        let lengths = exact_keys(&self.lengths);
         //filter functions
         let f_sheetname =
             |measurement: &MeasurementCompact| self.sheet_names.contains(&measurement.sheet_name);
         let f_width = |measurement: &MeasurementCompact| {
-            self.widths
-                .contains(&measurement.device.width.unwrap_or(0.001).to_string())
+            widths.contains(&exact_key(measurement.device.width.unwrap_or(0.001)))
         };
         let f_length = |measurement: &MeasurementCompact| {
-            self.lengths
-                .contains(&measurement.device.length.unwrap_or(0.001).to_string())
+            lengths.contains(&exact_key(measurement.device.length.unwrap_or(0.001)))
         };
         let f_temp = |measurement: &MeasurementCompact| {
             let temp_without_k: Vec<String> = self
@@ -52,11 +105,12 @@ impl FilterQuery {
                 .clone()
                 .contains(&measurement.device.temperature.unwrap_or(0).to_string())
         };
+        //an unknown process or die is an empty string, so such measurements can still be selected
         let f_process = |measurement: &MeasurementCompact| {
-            self.wafer == measurement.device.wafer.unwrap().to_string()
+            self.wafer == process_of(measurement)
         };
         let f_die = |measurement: &MeasurementCompact| {
-            let die = &measurement.device.die.clone().unwrap();
+            let die = measurement.device.die.clone().unwrap_or_default();
             self.dies.contains(&die)
         };
         let f_testtype = |measurement: &MeasurementCompact| {
@@ -86,6 +140,19 @@ impl FilterQuery {
             };
             msmnt_date >= bottom_range && msmnt_date <= top_range
         };
+        let f_settings = |measurement: &MeasurementCompact| {
+            self.settings
+                .iter()
+                .all(|setting| setting.matches(measurement))
+        };
+        let f_ranges = |measurement: &MeasurementCompact| {
+            self.ranges.iter().all(|range| range.matches(measurement))
+        };
+        let f_terminals = |measurement: &MeasurementCompact| {
+            self.terminals
+                .iter()
+                .all(|terminal| terminal.matches(measurement))
+        };
 
         let result: Vec<MeasurementCompact> =
             measurements.into_iter().filter(f_sheetname).collect();
@@ -97,10 +164,22 @@ impl FilterQuery {
         let result: Vec<MeasurementCompact> = result.into_iter().filter(f_testtype).collect();
         let result: Vec<MeasurementCompact> = result.into_iter().filter(f_speed).collect();
         let result: Vec<MeasurementCompact> = result.into_iter().filter(f_dates).collect();
+        let result: Vec<MeasurementCompact> = result.into_iter().filter(f_settings).collect();
+        let result: Vec<MeasurementCompact> = result.into_iter().filter(f_ranges).collect();
+        let result: Vec<MeasurementCompact> = result.into_iter().filter(f_terminals).collect();
         result
     }
 }
 
+fn process_of(measurement: &MeasurementCompact) -> String {
+    measurement
+        .device
+        .wafer
+        .as_ref()
+        .map(|process| process.to_string())
+        .unwrap_or_default()
+}
+
 impl From<FilterOptions> for FilterQuery {
     fn from(options: FilterOptions) -> Self {
         FilterQuery {
@@ -113,7 +192,7 @@ impl From<FilterOptions> for FilterQuery {
                 .keys()
                 .map(|a| a.clone())
                 .next()
-                .unwrap_or(String::from("MINOXG")),
+                .unwrap_or_default(),
             dies: options.dies.keys().map(|a| a.clone()).collect(),
             test_type: String::from("Sampling"),
             measurement_speeds: options
@@ -122,300 +201,38 @@ impl From<FilterOptions> for FilterQuery {
                 .map(|a| a.clone())
                 .collect(),
             dates_between: (None, None),
+            settings: vec![],
+            ranges: vec![],
+            terminals: vec![],
         }
     }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct FilterOptions {
-    sheet_names: HashMap<String, u32>,
-    widths: HashMap<String, u32>,
-    lengths: HashMap<String, u32>,
-    temps: HashMap<String, u32>,
-    processes: HashMap<String, u32>,
-    dies: HashMap<String, u32>,
-    test_types: HashMap<String, u32>,
-    measurement_speeds: HashMap<String, u32>,
-    dates: HashMap<String, u32>,
+    pub sheet_names: HashMap<String, u32>,
+    pub widths: HashMap<String, u32>,
+    pub lengths: HashMap<String, u32>,
+    pub temps: HashMap<String, u32>,
+    pub processes: HashMap<String, u32>,
+    pub dies: HashMap<String, u32>,
+    pub test_types: HashMap<String, u32>,
+    pub measurement_speeds: HashMap<String, u32>,
+    pub dates: HashMap<String, u32>,
+    //runs per bin of W, L, W/L, area and temperature, for ranges where the exact values are too many
+    #[serde(default)]
+    pub bins: BTreeMap<Quantity, Vec<Bin>>,
+    //runs per value of each property of each terminal
+    #[serde(default)]
+    pub terminals: TerminalOptions,
 }
-impl FilterOptions {
-    pub fn new(measurements: &Vec<MeasurementCompact>) -> FilterOptions {
-        let sheet_name_keys: Vec<String> = measurements
-            .into_iter()
-            .map(|msmnt| msmnt.sheet_name.clone())
-            .collect();
-        let sheet_names = sheet_name_keys.iter().fold(HashMap::new(), |mut acc, c| {
-            *acc.entry(c.clone()).or_insert(0) += 1 as u32;
-            acc
-        });
-        let width_keys: Vec<f64> = measurements
-            .into_iter()
-            .map(|msmnt| msmnt.device.width.unwrap_or(0.0))
-            .collect();
-        let widths = width_keys.iter().fold(HashMap::new(), |mut acc, c| {
-            *acc.entry(c.to_string()).or_insert(0) += 1 as u32;
-            acc
-        });
-        let length_keys: Vec<f64> = measurements
-            .into_iter()
-            .map(|msmnt| msmnt.device.length.unwrap_or(0.0))
-            .collect();
-        let lengths = length_keys.iter().fold(HashMap::new(), |mut acc, c| {
-            *acc.entry(c.to_string()).or_insert(0) += 1 as u32;
-            acc
-        });
-        let temp_keys: Vec<u32> = measurements
-            .into_iter()
-            .map(|msmnt| msmnt.device.temperature.unwrap_or(0))
-            .collect();
-        let temps = temp_keys.iter().fold(HashMap::new(), |mut acc, c| {
-            *acc.entry(format!("{}K", c.to_string())).or_insert(0) += 1 as u32;
-            acc
-        });
-        let process_keys: Vec<Process> = measurements
-            .into_iter()
-            .map(|msmnt| msmnt.device.wafer.unwrap())
-            .collect();
-        let processes = process_keys.iter().fold(HashMap::new(), |mut acc, c| {
-            *acc.entry(c.to_string()).or_insert(0) += 1 as u32;
-            acc
-        });
-        let die_keys: Vec<String> = measurements
-            .into_iter()
-            .map(|msmnt| msmnt.device.die.clone().unwrap_or(String::from("")))
-            .collect();
-        let dies = die_keys.iter().fold(HashMap::new(), |mut acc, c| {
-            *acc.entry(c.clone()).or_insert(0) += 1 as u32;
-            acc
-        });
-        let test_type_keys: Vec<&TestType> = measurements
-            .into_iter()
-            .map(|msmnt| &msmnt.test_parameter.test_type)
-            .collect();
-        let test_types = test_type_keys.iter().fold(HashMap::new(), |mut acc, c| {
-            *acc.entry(c.to_string()).or_insert(0) += 1 as u32;
-            acc
-        });
-        let measurement_speed_keys: Vec<&MeasurementSpeed> = measurements
-            .into_iter()
-            .map(|msmnt| &msmnt.test_parameter.measurement_speed)
-            .collect();
-        let measurement_speeds =
-            measurement_speed_keys
-                .iter()
-                .fold(HashMap::new(), |mut acc, c| {
-                    *acc.entry(c.to_string()).or_insert(0) += 1 as u32;
-                    acc
-                });
-        let date_keys: Vec<Date> = measurements
-            .into_iter()
-            .map(|msmnt| Date::from(msmnt.test_time_stamp))
-            .collect();
-        let dates = date_keys.iter().fold(HashMap::new(), |mut acc, c| {
-            *acc.entry(c.to_string()).or_insert(0) += 1 as u32;
-            acc
-        });
 
-        FilterOptions {
-            sheet_names,
-            widths,
-            lengths,
-            temps,
-            processes,
-            dies,
-            test_types,
-            measurement_speeds,
-            dates,
-        }
+impl FilterOptions {
+    pub fn new(measurements: &[MeasurementCompact]) -> FilterOptions {
+        FacetIndex::new(measurements).all()
     }
-    pub fn filtered(measurements: &Vec<MeasurementCompact>, filter: FilterQuery) -> FilterOptions {
-        let mut sheet_name_keys: Vec<String> = measurements
-            .clone()
-            .into_iter()
-            .map(|msmnt| msmnt.sheet_name.clone())
-            .collect();
-        sheet_name_keys.sort_unstable();
-        sheet_name_keys.dedup();
-        let filtern: FilterQuery = FilterQuery {
-            sheet_names: sheet_name_keys,
-            ..filter.clone()
-        };
-        let filteredn = filtern.filter(measurements.clone());
-        let sheet_name_keys: Vec<String> = filteredn
-            .into_iter()
-            .map(|msmnt| msmnt.sheet_name.clone())
-            .collect();
-        let sheet_names = sheet_name_keys.iter().fold(HashMap::new(), |mut acc, c| {
-            *acc.entry(c.clone()).or_insert(0) += 1 as u32;
-            acc
-        });
-
-        let mut width_keys: Vec<String> = measurements
-            .clone()
-            .into_iter()
-            .map(|msmnt| msmnt.device.width.unwrap_or(0.0).to_string())
-            .collect();
-        width_keys.sort_unstable();
-        width_keys.dedup();
-        let filtern: FilterQuery = FilterQuery {
-            widths: width_keys,
-            ..filter.clone()
-        };
-        let filteredn = filtern.filter(measurements.clone());
-        let width_keys: Vec<f64> = filteredn
-            .into_iter()
-            .map(|msmnt| msmnt.device.width.unwrap_or(0.0))
-            .collect();
-        let widths = width_keys.iter().fold(HashMap::new(), |mut acc, c| {
-            *acc.entry(c.to_string()).or_insert(0) += 1 as u32;
-            acc
-        });
-
-        let mut length_keys: Vec<String> = measurements
-            .clone()
-            .into_iter()
-            .map(|msmnt| msmnt.device.length.unwrap_or(0.0).to_string())
-            .collect();
-        length_keys.sort_unstable();
-        length_keys.dedup();
-        let filtern: FilterQuery = FilterQuery {
-            lengths: length_keys,
-            ..filter.clone()
-        };
-        let filteredn = filtern.filter(measurements.clone());
-        let length_keys: Vec<f64> = filteredn
-            .into_iter()
-            .map(|msmnt| msmnt.device.length.unwrap_or(0.0))
-            .collect();
-        let lengths = length_keys.iter().fold(HashMap::new(), |mut acc, c| {
-            *acc.entry(c.to_string()).or_insert(0) += 1 as u32;
-            acc
-        });
-
-        let mut temp_keys: Vec<String> = measurements
-            .clone()
-            .into_iter()
-            .map(|msmnt| msmnt.device.temperature.unwrap_or(0).to_string())
-            .collect();
-        temp_keys.sort_unstable();
-        temp_keys.dedup();
-        let filtern: FilterQuery = FilterQuery {
-            temps: temp_keys,
-            ..filter.clone()
-        };
-        let filteredn = filtern.filter(measurements.clone());
-        let temp_keys: Vec<u32> = filteredn
-            .into_iter()
-            .map(|msmnt| msmnt.device.temperature.unwrap_or(0))
-            .collect();
-        let temps = temp_keys.iter().fold(HashMap::new(), |mut acc, c| {
-            *acc.entry(format!("{}K", c.to_string())).or_insert(0) += 1 as u32;
-            acc
-        });
-
-        let mut process_keys: Vec<String> = measurements
-            .clone()
-            .into_iter()
-            .map(|msmnt| msmnt.device.wafer.unwrap().to_string())
-            .collect();
-        process_keys.sort_unstable();
-        process_keys.dedup();
-        let mut processes = HashMap::new();
-        for process_key in process_keys.into_iter() {
-            let filtern: FilterQuery = FilterQuery {
-                wafer: process_key.clone(),
-                ..filter.clone()
-            };
-            let filteredn = filtern.filter(measurements.clone());
-            processes.insert(process_key, filteredn.len() as u32);
-        }
-
-        let mut die_keys: Vec<String> = measurements
-            .clone()
-            .into_iter()
-            .map(|msmnt| msmnt.device.die.unwrap())
-            .collect();
-        die_keys.sort_unstable();
-        die_keys.dedup();
-        let filtern: FilterQuery = FilterQuery {
-            dies: die_keys,
-            ..filter.clone()
-        };
-        let filteredn = filtern.filter(measurements.clone());
-        let die_keys: Vec<String> = filteredn
-            .into_iter()
-            .map(|msmnt| msmnt.device.die.unwrap())
-            .collect();
-        let dies = die_keys.iter().fold(HashMap::new(), |mut acc, c| {
-            *acc.entry(c.clone()).or_insert(0) += 1 as u32;
-            acc
-        });
-
-        let mut test_type_keys: Vec<String> = measurements
-            .clone()
-            .into_iter()
-            .map(|msmnt| msmnt.test_parameter.test_type.to_string().clone())
-            .collect();
-        test_type_keys.sort_unstable();
-        test_type_keys.dedup();
-        let mut test_types = HashMap::new();
-        for test_type_key in test_type_keys.into_iter() {
-            let filtern: FilterQuery = FilterQuery {
-                test_type: test_type_key.clone(),
-                ..filter.clone()
-            };
-            let filteredn = filtern.filter(measurements.clone());
-            test_types.insert(test_type_key, filteredn.len() as u32);
-        }
-
-        let mut measurement_speed_keys: Vec<String> = measurements
-            .clone()
-            .into_iter()
-            .map(|msmnt| msmnt.test_parameter.measurement_speed.to_string())
-            .collect();
-        measurement_speed_keys.sort_unstable();
-        measurement_speed_keys.dedup();
-        let filtern: FilterQuery = FilterQuery {
-            measurement_speeds: measurement_speed_keys,
-            ..filter.clone()
-        };
-        let filteredn = filtern.filter(measurements.clone());
-        let measurement_speed_keys: Vec<MeasurementSpeed> = filteredn
-            .into_iter()
-            .map(|msmnt| msmnt.test_parameter.measurement_speed)
-            .collect();
-        let measurement_speeds =
-            measurement_speed_keys
-                .iter()
-                .fold(HashMap::new(), |mut acc, c| {
-                    *acc.entry(c.to_string()).or_insert(0) += 1 as u32;
-                    acc
-                });
-
-        let filtern: FilterQuery = FilterQuery {
-            dates_between: (None, None),
-            ..filter.clone()
-        };
-        let filteredn = filtern.filter(measurements.clone());
-        let date_keys: Vec<Date> = filteredn
-            .into_iter()
-            .map(|msmnt| Date::from(msmnt.test_time_stamp))
-            .collect();
-        let dates = date_keys.iter().fold(HashMap::new(), |mut acc, c| {
-            *acc.entry(c.to_string()).or_insert(0) += 1 as u32;
-            acc
-        });
-
-        FilterOptions {
-            sheet_names,
-            widths,
-            lengths,
-            temps,
-            processes,
-            dies,
-            test_types,
-            measurement_speeds,
-            dates,
-        }
+    //the counts of each facet are those of the runs the other facets of `filter` keep
+    pub fn filtered(measurements: &[MeasurementCompact], filter: FilterQuery) -> FilterOptions {
+        FacetIndex::new(measurements).options(measurements, &filter)
     }
 }
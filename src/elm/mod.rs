@@ -1,16 +1,76 @@
-use serde::{Deserialize, Serialize};
+use crate::database::saved::{Kind, Saved, SavedQuery};
+use crate::measurement::device::Device;
+use crate::measurement::series::Series;
+use crate::measurement::terminal_parameter::TerminalParameter;
+use crate::measurement::testdata::TestDataCompact;
+use crate::measurement::testparameter::TestParameter;
+use crate::measurement::timestamp::TimeStamp;
+use crate::measurement::MeasurementCompact;
+use serde::{Deserialize, Serialize, Serializer};
+
+pub mod facets;
 
 pub mod filter;
 
 pub mod process;
 
+pub mod range;
+
+pub mod terminal;
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(tag = "fromrust")]
 pub struct ToElm {
     pub message_nr: u32,
     pub task_done: Task,
-    pub measurements: Vec<crate::measurement::MeasurementCompact>,
+    #[serde(serialize_with = "listed")]
+    pub measurements: Vec<MeasurementCompact>,
     pub filter_options: filter::FilterOptions,
+    pub ingest_report: Vec<crate::ingest::FileReport>,
+    #[serde(default)]
+    pub scan: crate::ingest::scan::Progress,
+    //why the last text query could not be read
+    #[serde(default)]
+    pub query_error: Option<crate::query::ParseError>,
+    #[serde(default)]
+    pub saved: Saved,
+    //why the last change of the saved queries and collections failed
+    #[serde(default)]
+    pub saved_error: Option<String>,
+}
+
+//a run as the window lists it, the rows of its settings sheet are not shown and would make up most
+//of every message
+#[derive(Serialize)]
+struct Listed<'a> {
+    id: &'a str,
+    alias: &'a str,
+    file_path: &'a str,
+    sheet_name: &'a str,
+    device: &'a Device,
+    test_parameter: &'a TestParameter,
+    test_time_stamp: &'a TimeStamp,
+    terminals: &'a [TerminalParameter],
+    series: &'a [Series],
+    test_data: &'a [TestDataCompact],
+}
+
+fn listed<S: Serializer>(
+    measurements: &[MeasurementCompact],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(measurements.iter().map(|m| Listed {
+        id: &m.id,
+        alias: &m.alias,
+        file_path: &m.file_path,
+        sheet_name: &m.sheet_name,
+        device: &m.device,
+        test_parameter: &m.test_parameter,
+        test_time_stamp: &m.test_time_stamp,
+        terminals: &m.terminals,
+        series: &m.series,
+        test_data: &m.test_data,
+    }))
 }
 
 #[derive(Debug, Deserialize)]
@@ -18,8 +78,31 @@ pub struct ToElm {
 pub enum FromElm {
     Init,
     Log(String),
-    Filter(filter::FilterQuery),
+    Filter(Box<filter::FilterQuery>),
+    //a filter written in the query language, e.g. "wafer = GF22 and W >= 1um"
+    Query(String),
     Process(process::ProcessQuery),
+    //keeps the filters of the filter page or a text query under a name
+    SaveQuery {
+        name: String,
+        query: SavedQuery,
+    },
+    //keeps the given runs (ids or aliases) under a name
+    SaveCollection {
+        name: String,
+        ids: Vec<String>,
+    },
+    Rename {
+        kind: Kind,
+        name: String,
+        to: String,
+    },
+    Delete {
+        kind: Kind,
+        name: String,
+    },
+    //shows the runs of a collection
+    Collection(String),
 }
 
 //{"torust":{"Log":"updated model"}}
@@ -28,4 +111,47 @@ pub enum Task {
     Init,
     Filtering,
     Processing,
+    //progress of the library scan
+    Scanning,
+    //the scan is done and the measurements are updated
+    Scanned,
+    //a query or collection was saved, renamed or deleted
+    Saved,
+}
+
+//the runs the user selected last, so a finished scan shows the same selection
+#[derive(Debug, Clone)]
+pub enum Selection {
+    Filter(Box<filter::FilterQuery>),
+    Query(crate::query::Query),
+    Collection(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::measurement::settings::Settings;
+
+    //the runs are sent as they are kept, only without their settings
+    #[test]
+    fn runs_are_sent_without_settings() {
+        let mut run = facets::tests::run(7);
+        Settings::add(&mut run.settings.global, "Sweep Delay", "1E-3".to_string());
+        let mut kept = serde_json::to_value(&run).unwrap();
+        assert!(kept["settings"]["global"]["Sweep Delay"].is_string());
+        let to_elm = ToElm {
+            message_nr: 1,
+            task_done: Task::Filtering,
+            filter_options: filter::FilterOptions::new(std::slice::from_ref(&run)),
+            measurements: vec![run],
+            ingest_report: vec![],
+            scan: Default::default(),
+            query_error: None,
+            saved: Default::default(),
+            saved_error: None,
+        };
+        let sent = serde_json::to_value(&to_elm).unwrap();
+        kept.as_object_mut().unwrap().remove("settings");
+        assert_eq!(sent["measurements"][0], kept);
+    }
 }
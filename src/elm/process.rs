@@ -1,6 +1,9 @@
+use crate::measurement::process::{ProcessDefinition, ProcessRegistry};
+use crate::measurement::series::Series;
+use crate::measurement::settings::Settings;
 use crate::measurement::testdata::{terminal::Terminal, units::Unit};
 use crate::measurement::testdata::{TestData, TestDataCompact};
-use crate::measurement::Measurement;
+use crate::measurement::{Measurement, MeasurementCompact};
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::fs::{self};
@@ -12,25 +15,35 @@ pub struct ProcessQuery {
     what: Vec<ProcessingType>,
     combined: bool,
     from: Vec<ProcessData>,
+    //a saved collection whose runs are processed with all their data, next to those of `from`
+    #[serde(default)]
+    collection: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct DataSeries {
     title: String,
     data: Vec<ExportData>,
+    settings: Settings,
+    //technology data (tox, Cox, VDD) of the process for normalisations in the scripts
+    process: Option<ProcessDefinition>,
 }
 #[derive(Debug, Serialize)]
 pub struct ExportData {
     measurement_id: String,
     designator: String,
+    //label of every vector in data, e.g. "Vg=1.5" or "Run 2"
+    series: Vec<String>,
     data: Vec<Vec<f64>>,
 }
 
 impl ExportData {
-    fn from_testdata(id: String, testdata: TestData) -> ExportData {
+    fn from_testdata(id: String, testdata: TestData, series: &[Series]) -> ExportData {
         let designator: String = {
             if testdata.terminal == Terminal::Time {
                 format!("T(s)")
+            } else if testdata.terminal == Terminal::Other {
+                testdata.name.clone()
             } else {
                 format!(
                     "{}{}",
@@ -39,17 +52,69 @@ impl ExportData {
                 )
             }
         };
+        let series = testdata
+            .series_numbers()
+            .iter()
+            .map(|number| {
+                series
+                    .iter()
+                    .find(|s| s.number == *number)
+                    .map(|s| s.label.clone())
+                    .unwrap_or_else(|| format!("Run {}", number))
+            })
+            .collect();
         let data = testdata.data;
         ExportData {
             measurement_id: id,
             designator,
+            series,
             data,
         }
     }
 }
 
 impl ProcessQuery {
-    pub fn process(&self, measurements: Vec<Measurement>, output_dir: &str, script_dir: &str) {
+    pub fn collection(&self) -> Option<&str> {
+        self.collection.as_deref()
+    }
+
+    //adds the runs of the collection that are not in `from` yet, with every series of their data
+    pub fn add_runs(&mut self, runs: &[MeasurementCompact]) {
+        for run in runs {
+            if self.from.iter().any(|pd| pd.id == run.id) {
+                continue;
+            }
+            let data = run
+                .test_data
+                .iter()
+                .map(|t| TestDataCompact {
+                    series: if t.series.is_empty() {
+                        (1..=t.count).collect()
+                    } else {
+                        t.series.clone()
+                    },
+                    ..t.clone()
+                })
+                .collect();
+            self.from.push(ProcessData {
+                id: run.id.clone(),
+                data,
+            });
+        }
+    }
+
+    //ids of the runs to be processed
+    pub fn ids(&self) -> Vec<String> {
+        self.from.iter().map(|pd| pd.id.clone()).collect()
+    }
+
+    pub fn process(
+        &self,
+        measurements: Vec<Measurement>,
+        processes: &ProcessRegistry,
+        output_dir: &str,
+        script_dir: &str,
+    ) {
         let selected_measurements: Vec<Measurement> = {
             let ids: Vec<(String, Vec<TestDataCompact>)> = self
                 .from
@@ -87,8 +152,8 @@ impl ProcessQuery {
         let diff_wafer: bool = {
             let mut stringy = selected_measurements
                 .iter()
-                .filter_map(|m| m.device.wafer)
-                .map(|p| p.to_string())
+                .filter_map(|m| m.device.wafer.as_ref())
+                .map(|p| processes.resolve(&p.0).to_string())
                 .collect::<Vec<String>>();
             stringy.sort_unstable();
             stringy.dedup();
@@ -193,14 +258,21 @@ impl ProcessQuery {
                                 .collect::<Vec<TestData>>(),
                         };
                         temp.into_iter()
-                            .map(|t| ExportData::from_testdata(m.id.clone(), t))
+                            .map(|t| ExportData::from_testdata(m.id.clone(), t, &m.series))
                             .collect::<Vec<ExportData>>()
                     };
+                    let process = m
+                        .device
+                        .wafer
+                        .as_ref()
+                        .and_then(|w| processes.definition(&w.0))
+                        .cloned();
                     let title: String = {
                         let wafer = if diff_wafer {
                             m.device
                                 .wafer
-                                .and_then(|w| Some(format!("P={} ", w)))
+                                .as_ref()
+                                .map(|w| format!("P={} ", processes.resolve(&w.0)))
                                 .unwrap_or("".to_string())
                         } else {
                             "".to_string()
@@ -269,7 +341,12 @@ impl ProcessQuery {
                         format!("{}{}{}{}{}{}{}", wafer, die, speed, temp, width, length, id)
                     };
                     //println!("title:{}", title);
-                    DataSeries { title, data }
+                    DataSeries {
+                        title,
+                        data,
+                        settings: m.settings.clone(),
+                        process,
+                    }
                 })
                 .collect::<Vec<DataSeries>>();
 
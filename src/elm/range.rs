@@ -0,0 +1,198 @@
+use crate::measurement::MeasurementCompact;
+use serde::{Deserialize, Serialize};
+
+//numbers of a run that can be selected by range and are counted in bins
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Quantity {
+    //nm
+    Width,
+    //nm
+    Length,
+    //W/L
+    Ratio,
+    //nm²
+    Area,
+    //K
+    Temperature,
+}
+
+pub const QUANTITIES: [Quantity; 5] = [
+    Quantity::Width,
+    Quantity::Length,
+    Quantity::Ratio,
+    Quantity::Area,
+    Quantity::Temperature,
+];
+
+const PREFIXES: [(&str, f64); 8] = [
+    ("G", 1e9),
+    ("M", 1e6),
+    ("k", 1e3),
+    ("", 1.0),
+    ("m", 1e-3),
+    ("µ", 1e-6),
+    ("n", 1e-9),
+    ("p", 1e-12),
+];
+
+//a number rounded to 6 significant digits, so 250 and 250.00000001 are the same value
+pub fn exact_key(value: f64) -> String {
+    if value == 0.0 || !value.is_finite() {
+        return value.to_string();
+    }
+    let scale = 10f64.powi(5 - value.abs().log10().floor() as i32);
+    ((value * scale).round() / scale).to_string()
+}
+
+//"500 nm", "0.05 µm²" or "77 K", `power` is that of the unit, as the prefix of µm² counts twice
+//areas keep a prefix down to 0.001 of it
+fn si(value: f64, unit: &str, power: i32) -> String {
+    if value == 0.0 {
+        return format!("0 {}", unit);
+    }
+    let smallest = 1e-3f64.powi(power - 1) * (1.0 - 1e-9);
+    let (prefix, factor) = PREFIXES
+        .iter()
+        .find(|(_, factor)| value.abs() >= factor.powi(power) * smallest)
+        .unwrap_or(&PREFIXES[PREFIXES.len() - 1]);
+    format!(
+        "{} {}{}",
+        exact_key(value / factor.powi(power)),
+        prefix,
+        unit
+    )
+}
+
+//relative tolerance of range bounds and bin edges
+fn widen(value: f64, direction: f64) -> f64 {
+    value + direction * 1e-9 * value.abs()
+}
+
+impl Quantity {
+    pub fn of(self, measurement: &MeasurementCompact) -> Option<f64> {
+        let device = &measurement.device;
+        match self {
+            Quantity::Width => device.width,
+            Quantity::Length => device.length,
+            Quantity::Ratio => match (device.width, device.length) {
+                (Some(width), Some(length)) if length > 0.0 => Some(width / length),
+                _ => None,
+            },
+            Quantity::Area => Some(device.width? * device.length?),
+            Quantity::Temperature => device.temperature.map(|temperature| temperature as f64),
+        }
+    }
+
+    pub fn label(self, value: f64) -> String {
+        match self {
+            Quantity::Width | Quantity::Length => si(value * 1e-9, "m", 1),
+            Quantity::Area => si(value * 1e-18, "m²", 2),
+            Quantity::Ratio => exact_key(value),
+            Quantity::Temperature => si(value, "K", 1),
+        }
+    }
+
+    //[min, max) of the bin of a value, the edges are 1, 2 and 5 times a power of ten,
+    //temperatures from 100 K on are binned every 50 K
+    pub fn bin_of(self, value: f64) -> Option<(f64, f64)> {
+        if value <= 0.0 || !value.is_finite() {
+            return None;
+        }
+        let value = widen(value, 1.0);
+        if self == Quantity::Temperature && value >= 100.0 {
+            let min = (value / 50.0).floor() * 50.0;
+            return Some((min, min + 50.0));
+        }
+        let mut decade = 10f64.powf(value.log10().floor());
+        if value / decade >= 10.0 {
+            decade *= 10.0;
+        }
+        let (min, max) = match value / decade {
+            scaled if scaled < 2.0 => (1.0, 2.0),
+            scaled if scaled < 5.0 => (2.0, 5.0),
+            _ => (5.0, 10.0),
+        };
+        Some((min * decade, max * decade))
+    }
+}
+
+//keeps the runs with a value from `min` to `max`, runs without the value are left out
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RangeFilter {
+    pub quantity: Quantity,
+    #[serde(default)]
+    pub min: Option<f64>,
+    #[serde(default)]
+    pub max: Option<f64>,
+    //leaves out `max` itself, as a bin does
+    #[serde(default)]
+    pub below_max: bool,
+}
+
+//the bounds with a relative tolerance, a value must be at least the first and
+//below (or with `below_max` not above) the second
+pub fn bounds(min: Option<f64>, max: Option<f64>, below_max: bool) -> (Option<f64>, Option<f64>) {
+    let direction = if below_max { -1.0 } else { 1.0 };
+    (
+        min.map(|min| widen(min, -1.0)),
+        max.map(|max| widen(max, direction)),
+    )
+}
+
+pub fn within(value: f64, min: Option<f64>, max: Option<f64>, below_max: bool) -> bool {
+    let (low, high) = bounds(min, max, below_max);
+    low.is_none_or(|low| value >= low)
+        && high.is_none_or(|high| {
+            if below_max {
+                value < high
+            } else {
+                value <= high
+            }
+        })
+}
+
+impl RangeFilter {
+    pub fn bounds(&self) -> (Option<f64>, Option<f64>) {
+        bounds(self.min, self.max, self.below_max)
+    }
+
+    pub fn contains(&self, value: f64) -> bool {
+        within(value, self.min, self.max, self.below_max)
+    }
+
+    pub fn matches(&self, measurement: &MeasurementCompact) -> bool {
+        self.quantity
+            .of(measurement)
+            .is_some_and(|value| self.contains(value))
+    }
+}
+
+//runs per bin of a quantity, labelled like "500 nm – 1 µm"
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Bin {
+    pub label: String,
+    pub min: f64,
+    pub max: f64,
+    pub runs: u32,
+}
+
+//the bins that hold at least one of the values, ascending
+pub fn bins<I: IntoIterator<Item = f64>>(quantity: Quantity, values: I) -> Vec<Bin> {
+    let mut bins: Vec<Bin> = vec![];
+    for (min, max) in values
+        .into_iter()
+        .filter_map(|value| quantity.bin_of(value))
+    {
+        match bins.iter_mut().find(|bin| bin.min == min) {
+            Some(bin) => bin.runs += 1,
+            None => bins.push(Bin {
+                label: format!("{} – {}", quantity.label(min), quantity.label(max)),
+                min,
+                max,
+                runs: 1,
+            }),
+        }
+    }
+    bins.sort_by(|a, b| a.min.partial_cmp(&b.min).unwrap());
+    bins
+}
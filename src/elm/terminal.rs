@@ -0,0 +1,115 @@
+use super::range::{self, exact_key};
+use crate::measurement::terminal_parameter::crange::CRange;
+use crate::measurement::terminal_parameter::terminal::Terminal;
+use crate::measurement::terminal_parameter::TerminalParameter;
+use crate::measurement::MeasurementCompact;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+//what of a terminal column of the "Settings" sheet is offered and filtered
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Property {
+    //"VoltageBias", "VoltageLinearSweep", ...
+    Mode,
+    Instrument,
+    //V or A, the numbers are offered as 6 significant digits
+    Bias,
+    Start,
+    Stop,
+    Step,
+    Compliance,
+    VoltageRange,
+    //"Auto" or "Limited 1nA"
+    CurrentRange,
+    //"Measured" or "Programmed"
+    Voltage,
+    Current,
+}
+
+//counts per terminal, property and value
+pub type TerminalOptions = BTreeMap<Terminal, BTreeMap<Property, HashMap<String, u32>>>;
+
+//the properties a terminal has, as text and for numbers also as number
+pub fn values_of(parameter: &TerminalParameter) -> Vec<(Property, String, Option<f64>)> {
+    let mode = &parameter.operational_mode;
+    let mut values = vec![
+        (Property::Mode, format!("{:?}", mode.op_type), None),
+        (
+            Property::Instrument,
+            format!("{:?}", parameter.instrument),
+            None,
+        ),
+    ];
+    let numbers = [
+        (Property::Bias, mode.bias),
+        (Property::Start, mode.start),
+        (Property::Stop, mode.stop),
+        (Property::Step, mode.stepsize),
+        (Property::Compliance, parameter.compliance),
+    ];
+    for (property, number) in numbers.iter() {
+        if let Some(number) = number {
+            values.push((*property, exact_key(*number), Some(*number)));
+        }
+    }
+    if let Some(range) = parameter.voltage_range {
+        values.push((Property::VoltageRange, format!("{:?}", range), None));
+    }
+    if let Some(range) = parameter.current_range.as_ref() {
+        let range = match range {
+            CRange::Auto => "Auto".to_string(),
+            CRange::LimitedAuto(limit) => format!("Limited {}", limit),
+        };
+        values.push((Property::CurrentRange, range, None));
+    }
+    if let Some(voltage) = parameter.voltage {
+        values.push((Property::Voltage, format!("{:?}", voltage), None));
+    }
+    if let Some(current) = parameter.current {
+        values.push((Property::Current, format!("{:?}", current), None));
+    }
+    values
+}
+
+//keeps runs with a terminal whose property is one of `values` and from `min` to `max`,
+//e.g. the gate on SMU2 or a drain bias of 50mV
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TerminalFilter {
+    pub terminal: Terminal,
+    pub property: Property,
+    //any value when empty
+    #[serde(default)]
+    pub values: Vec<String>,
+    #[serde(default)]
+    pub min: Option<f64>,
+    #[serde(default)]
+    pub max: Option<f64>,
+}
+
+impl TerminalFilter {
+    //the bounds with the tolerance of a range filter
+    pub fn bounds(&self) -> (Option<f64>, Option<f64>) {
+        range::bounds(self.min, self.max, false)
+    }
+
+    //the values as they are offered, "0.050" is the same as "0.05"
+    pub fn keys(&self) -> Vec<String> {
+        super::filter::exact_keys(&self.values)
+    }
+
+    pub fn matches(&self, measurement: &MeasurementCompact) -> bool {
+        let keys = self.keys();
+        measurement
+            .terminals
+            .iter()
+            .filter(|parameter| parameter.terminal == self.terminal)
+            .flat_map(values_of)
+            .any(|(property, value, number)| {
+                property == self.property
+                    && (keys.is_empty() || keys.iter().any(|key| key.eq_ignore_ascii_case(&value)))
+                    && (self.min.is_none() && self.max.is_none()
+                        || number
+                            .is_some_and(|number| range::within(number, self.min, self.max, false)))
+            })
+    }
+}
@@ -0,0 +1,458 @@
+use super::{ErrorKind, Importer, IngestError};
+use crate::measurement::device::Device;
+use crate::measurement::metadata::MetadataParser;
+use crate::measurement::series::Series;
+use crate::measurement::settings::Settings;
+use crate::measurement::testdata::{terminal::Terminal, units::Unit, TestData};
+use crate::measurement::testparameter::{MeasurementSpeed, TestParameter, TestType};
+use crate::measurement::timestamp::TimeStamp;
+use crate::measurement::Measurement;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+//describes how the columns and header lines of a csv/txt export map onto a measurement
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Profile {
+    pub name: String,
+    pub extensions: Vec<String>,
+    //only files whose relative path matches use this profile
+    #[serde(default)]
+    pub file_pattern: Option<String>,
+    //detected from the header line when absent, whitespace is used when no ',', ';' or tab is found
+    #[serde(default)]
+    pub delimiter: Option<char>,
+    //regex of the header line, e.g. "^DataName", the first line with a cell matching a column
+    //is taken when absent
+    #[serde(default)]
+    pub header_pattern: Option<String>,
+    //first cell of the data lines, e.g. "DataValue", every line below the header when absent
+    #[serde(default)]
+    pub data_prefix: Option<String>,
+    pub columns: Vec<ColumnMapping>,
+    #[serde(default)]
+    pub metadata: Vec<MetadataMapping>,
+    #[serde(default = "default_test_type")]
+    pub test_type: TestType,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ColumnMapping {
+    pub header: String,
+    pub terminal: Terminal,
+    pub unit: Unit,
+}
+
+//the first capture group of the pattern holds the value of the field
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MetadataMapping {
+    pub pattern: String,
+    pub field: MetadataField,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MetadataField {
+    TimeStamp,
+    TestType,
+    MeasurementSpeed,
+    HoldTime,
+    SweepDelay,
+    IntervalTime,
+}
+
+fn default_test_type() -> TestType {
+    TestType::Sweeping
+}
+
+struct CompiledProfile {
+    profile: Profile,
+    file_pattern: Option<Regex>,
+    header_pattern: Option<Regex>,
+    columns: Vec<(Regex, Terminal, Unit)>,
+    metadata: Vec<(Regex, MetadataField)>,
+}
+
+//csv/txt files of other instruments, read with the first profile that fits the file
+pub struct Delimited {
+    profiles: Vec<CompiledProfile>,
+    metadata: MetadataParser,
+}
+
+impl Delimited {
+    pub fn new(profiles: &[Profile], metadata: MetadataParser) -> Result<Delimited, regex::Error> {
+        let profiles = profiles
+            .iter()
+            .map(|profile| {
+                Ok(CompiledProfile {
+                    profile: profile.clone(),
+                    file_pattern: profile
+                        .file_pattern
+                        .as_ref()
+                        .map(|pattern| Regex::new(pattern))
+                        .transpose()?,
+                    header_pattern: profile
+                        .header_pattern
+                        .as_ref()
+                        .map(|pattern| Regex::new(pattern))
+                        .transpose()?,
+                    columns: profile
+                        .columns
+                        .iter()
+                        .map(|c| Ok((Regex::new(&c.header)?, c.terminal, c.unit)))
+                        .collect::<Result<_, regex::Error>>()?,
+                    metadata: profile
+                        .metadata
+                        .iter()
+                        .map(|m| Ok((Regex::new(&m.pattern)?, m.field)))
+                        .collect::<Result<_, regex::Error>>()?,
+                })
+            })
+            .collect::<Result<Vec<CompiledProfile>, regex::Error>>()?;
+        Ok(Delimited { profiles, metadata })
+    }
+}
+
+impl Importer for Delimited {
+    fn accepts(&self, extension: &str) -> bool {
+        self.profiles.iter().any(|compiled| {
+            compiled
+                .profile
+                .extensions
+                .iter()
+                .any(|ext| ext.eq_ignore_ascii_case(extension))
+        })
+    }
+
+    fn import(&self, root: &str, relative_path: &str) -> Result<Vec<Measurement>, IngestError> {
+        let path = super::path::absolute(root, relative_path);
+        let bytes = std::fs::read(&path)
+            .map_err(|e| IngestError::new(ErrorKind::Open(e.to_string())).in_file(relative_path))?;
+        let text = String::from_utf8_lossy(&bytes);
+        let lines: Vec<&str> = text.lines().collect();
+        let extension = Path::new(relative_path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or("");
+
+        self.profiles
+            .iter()
+            .filter(|compiled| {
+                compiled
+                    .profile
+                    .extensions
+                    .iter()
+                    .any(|ext| ext.eq_ignore_ascii_case(extension))
+                    && compiled
+                        .file_pattern
+                        .as_ref()
+                        .is_none_or(|pattern| pattern.is_match(relative_path))
+            })
+            .find_map(|compiled| compiled.read(&lines, relative_path, &self.metadata))
+            .unwrap_or_else(|| Err(IngestError::new(ErrorKind::NoMatchingProfile)))
+            .map(|measurement| vec![measurement])
+            .map_err(|e| e.in_file(relative_path))
+    }
+}
+
+fn split(line: &str, delimiter: Option<char>) -> Vec<&str> {
+    match delimiter {
+        Some(delimiter) => line.split(delimiter).map(|cell| cell.trim()).collect(),
+        None => line.split_whitespace().collect(),
+    }
+}
+
+impl CompiledProfile {
+    fn delimiter(&self, header: &str) -> Option<char> {
+        self.profile.delimiter.or_else(|| {
+            [',', ';', '\t']
+                .iter()
+                .copied()
+                .find(|d| header.contains(*d))
+        })
+    }
+
+    //"label, value" or "label, sub label, value" as written by the B1500, "# label: value" otherwise
+    fn settings_entry(&self, line: &str) -> Option<(String, String)> {
+        let line = line.trim_start_matches('#').trim();
+        let mut cells: Vec<&str> = split(line, self.delimiter(line));
+        if self.delimiter(line).is_none() || cells.len() < 2 {
+            cells = line.splitn(2, ':').map(|cell| cell.trim()).collect();
+        }
+        let (value, label) = cells.split_last()?;
+        let label = label.join(" ");
+        if label.is_empty() || value.is_empty() {
+            None
+        } else {
+            Some((label, value.to_string()))
+        }
+    }
+
+    fn column_of(&self, header: &str) -> Option<(Terminal, Unit)> {
+        self.columns
+            .iter()
+            .find(|(pattern, _, _)| pattern.is_match(header))
+            .map(|(_, terminal, unit)| (*terminal, *unit))
+    }
+
+    //None when the profile does not fit the file, so the next profile can be tried
+    fn read(
+        &self,
+        lines: &[&str],
+        relative_path: &str,
+        metadata: &MetadataParser,
+    ) -> Option<Result<Measurement, IngestError>> {
+        let header_row = match self.header_pattern.as_ref() {
+            Some(pattern) => lines.iter().position(|line| pattern.is_match(line))?,
+            None => lines.iter().position(|line| {
+                split(line, self.delimiter(line))
+                    .iter()
+                    .any(|cell| self.column_of(cell).is_some())
+            })?,
+        };
+        let delimiter = self.delimiter(lines[header_row]);
+        let columns: Vec<(usize, &str, Terminal, Unit)> = split(lines[header_row], delimiter)
+            .into_iter()
+            .enumerate()
+            .filter(|(_, cell)| !cell.is_empty())
+            .map(|(i, cell)| {
+                let (terminal, unit) = self
+                    .column_of(cell)
+                    .unwrap_or((Terminal::Other, Unit::from_header(cell)));
+                (i, cell, terminal, unit)
+            })
+            .collect();
+        Some(self.measurement(
+            lines,
+            header_row,
+            delimiter,
+            &columns,
+            relative_path,
+            metadata,
+        ))
+    }
+
+    fn measurement(
+        &self,
+        lines: &[&str],
+        header_row: usize,
+        delimiter: Option<char>,
+        columns: &[(usize, &str, Terminal, Unit)],
+        relative_path: &str,
+        metadata: &MetadataParser,
+    ) -> Result<Measurement, IngestError> {
+        //lines above the header are kept as settings and searched for metadata
+        let mut settings = Settings::default();
+        let mut fields: HashMap<MetadataField, (usize, String)> = HashMap::new();
+        for (row, line) in lines.iter().enumerate().take(header_row) {
+            if let Some((label, value)) = self.settings_entry(line) {
+                Settings::add(&mut settings.global, &label, value);
+            }
+            for (pattern, field) in self.metadata.iter() {
+                if let Some(value) = pattern.captures(line).and_then(|c| c.get(1)) {
+                    fields
+                        .entry(*field)
+                        .or_insert_with(|| (row, value.as_str().trim().to_string()));
+                }
+            }
+        }
+        let number = |field: MetadataField| -> Result<Option<f64>, IngestError> {
+            match fields.get(&field) {
+                Some((row, value)) => value
+                    .parse::<f64>()
+                    .map(Some)
+                    .map_err(|_| IngestError::unexpected("a number", value).at((*row as u32, 0))),
+                None => Ok(None),
+            }
+        };
+
+        let test_time_stamp = match fields.get(&MetadataField::TimeStamp) {
+            Some((row, value)) => TimeStamp::parse(value).ok_or_else(|| {
+                IngestError::unexpected("a time stamp", value).at((*row as u32, 0))
+            })?,
+            None => {
+                return Err(IngestError::new(ErrorKind::MissingLabel(
+                    "time stamp".to_string(),
+                )))
+            }
+        };
+        let test_type = match fields.get(&MetadataField::TestType) {
+            Some((_, value)) if value.eq_ignore_ascii_case("sampling") => TestType::Sampling,
+            Some((_, value)) if value.eq_ignore_ascii_case("sweeping") => TestType::Sweeping,
+            Some((row, value)) => {
+                return Err(
+                    IngestError::unexpected("'Sweeping' or 'Sampling'", value).at((*row as u32, 0))
+                )
+            }
+            None => self.profile.test_type.clone(),
+        };
+        let measurement_speed = match fields.get(&MetadataField::MeasurementSpeed) {
+            Some((_, value)) if value.eq_ignore_ascii_case("fast") => MeasurementSpeed::Fast,
+            Some((_, value)) if value.eq_ignore_ascii_case("quiet") => MeasurementSpeed::Quiet,
+            Some((_, value)) if value.eq_ignore_ascii_case("normal") => MeasurementSpeed::Normal,
+            Some(_) => MeasurementSpeed::Custom,
+            None => MeasurementSpeed::Normal,
+        };
+        let test_parameter = TestParameter {
+            test_type,
+            measurement_speed,
+            ad_aperture: None,
+            filter_factor: None,
+            interval_time: number(MetadataField::IntervalTime)?,
+            sweep_delay_time: number(MetadataField::SweepDelay)?,
+            hold_time: number(MetadataField::HoldTime)?.unwrap_or(0.0),
+        };
+
+        let file_name = Path::new(relative_path)
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("")
+            .to_string();
+        let device = Device::extract(metadata, relative_path, file_name.as_str())?;
+
+        //the lines are read whole so the columns stay aligned, columns without any number (e.g.
+        //"DataName") are left out and a line missing a number of the others is skipped
+        let rows: Vec<Vec<Option<f64>>> = lines
+            .iter()
+            .skip(header_row + 1)
+            .map(|line| split(line, delimiter))
+            .filter(|cells| match self.profile.data_prefix.as_ref() {
+                Some(prefix) => cells.first() == Some(&prefix.as_str()),
+                None => cells.iter().any(|cell| !cell.is_empty()),
+            })
+            .map(|cells| {
+                columns
+                    .iter()
+                    .map(|(column, _, _, _)| {
+                        cells.get(*column).and_then(|cell| cell.parse::<f64>().ok())
+                    })
+                    .collect()
+            })
+            .collect();
+        let numeric: Vec<usize> = (0..columns.len())
+            .filter(|n| rows.iter().any(|row| row[*n].is_some()))
+            .collect();
+        let rows: Vec<Vec<f64>> = rows
+            .iter()
+            .filter_map(|row| numeric.iter().map(|n| row[*n]).collect())
+            .collect();
+
+        //mapped columns with the same terminal and unit become series of one channel,
+        //unmapped columns are kept as channels named after their header
+        let mut test_data: Vec<TestData> = vec![];
+        for (position, n) in numeric.iter().enumerate() {
+            let (_, header, terminal, unit) = &columns[*n];
+            let data: Vec<f64> = rows.iter().map(|row| row[position]).collect();
+            match test_data.iter_mut().find(|t| match terminal {
+                Terminal::Other => t.name == *header,
+                _ => t.terminal == *terminal && t.unit == *unit,
+            }) {
+                Some(existing) => {
+                    existing.series.push(existing.data.len() + 1);
+                    existing.data.push(data);
+                }
+                None => test_data.push(TestData {
+                    terminal: *terminal,
+                    unit: *unit,
+                    name: header.to_string(),
+                    series: vec![1],
+                    data: vec![data],
+                }),
+            }
+        }
+
+        Ok(Measurement {
+            id: String::new(),
+            alias: String::new(),
+            file_path: relative_path.to_string(),
+            sheet_name: file_name,
+            device,
+            test_parameter,
+            test_time_stamp,
+            terminals: vec![],
+            series: Series::from_run(&[], &test_data),
+            settings,
+            test_data,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::measurement::process::Process;
+
+    const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/csv");
+    const B1500: &str = "Process=GF22 Die=A1 T=293K/W=1um L=0.5um idvg.csv";
+
+    fn delimited(config: &Config) -> Delimited {
+        let metadata = MetadataParser::new(&config.metadata, &config.process_registry()).unwrap();
+        Delimited::new(&config.csv_profiles, metadata).unwrap()
+    }
+
+    fn config() -> Config {
+        Config::load(concat!(env!("CARGO_MANIFEST_DIR"), "/config.json")).unwrap()
+    }
+
+    fn data(measurement: &Measurement, terminal: Terminal, unit: Unit) -> &[Vec<f64>] {
+        &measurement
+            .test_data
+            .iter()
+            .find(|t| t.terminal == terminal && t.unit == unit)
+            .unwrap()
+            .data
+    }
+
+    //the channel names above "DataName" are not taken for the header and the line without Ig
+    //is skipped in every column
+    #[test]
+    fn reads_b1500_export_by_rows() {
+        let measurements = delimited(&config()).import(FIXTURES, B1500).unwrap();
+        assert_eq!(measurements.len(), 1);
+        let measurement = &measurements[0];
+        assert_eq!(
+            data(measurement, Terminal::Gate, Unit::Voltage),
+            &[vec![0.0, 0.5, 1.5]]
+        );
+        assert_eq!(
+            data(measurement, Terminal::Drain, Unit::Current),
+            &[vec![1.2e-12, 2.5e-6, 1.8e-5]]
+        );
+        assert_eq!(
+            data(measurement, Terminal::Gate, Unit::Current),
+            &[vec![3e-13, 1e-13, 2e-13]]
+        );
+        let gm = measurement
+            .test_data
+            .iter()
+            .find(|t| t.terminal == Terminal::Other)
+            .unwrap();
+        assert_eq!(gm.name, "gm");
+        assert_eq!(gm.data, vec![vec![0.0, 1.1e-5, 1.6e-5]]);
+        assert_eq!(measurement.test_data.len(), 4);
+        assert_eq!(
+            measurement.test_time_stamp,
+            TimeStamp::parse("11/21/2019 13:30:00").unwrap()
+        );
+        assert_eq!(measurement.test_parameter.sweep_delay_time, Some(0.001));
+        assert_eq!(
+            measurement
+                .settings
+                .global
+                .get("TestParameter Timing.Sweep.Hold"),
+            Some(&"0".to_string())
+        );
+        assert_eq!(measurement.device.wafer, Some(Process("GF22".to_string())));
+        assert_eq!(measurement.device.width, Some(1000.0));
+    }
+
+    //a file without the header line of the profile is not read with it
+    #[test]
+    fn header_pattern_must_match() {
+        let mut config = config();
+        config.csv_profiles[0].header_pattern = Some("^Header".to_string());
+        let error = delimited(&config).import(FIXTURES, B1500).unwrap_err();
+        assert_eq!(error.kind, ErrorKind::NoMatchingProfile);
+    }
+}
@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+
+//what went wrong while reading a workbook
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum ErrorKind {
+    Open(String),
+    //a directory of the library that cannot be listed
+    Directory(String),
+    MissingSheet(String),
+    EmptySheet,
+    MissingCell,
+    MissingLabel(String),
+    Unexpected { expected: String, found: String },
+    MissingMetadata(String),
+    NoMatchingProfile,
+}
+
+impl std::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::Open(reason) => write!(f, "cannot open workbook: {}", reason),
+            ErrorKind::Directory(reason) => write!(f, "cannot read directory: {}", reason),
+            ErrorKind::MissingSheet(name) => write!(f, "cannot find sheet '{}'", name),
+            ErrorKind::EmptySheet => write!(f, "sheet is empty"),
+            ErrorKind::MissingCell => write!(f, "cell is empty or missing"),
+            ErrorKind::MissingLabel(label) => write!(f, "cannot find a row labelled '{}'", label),
+            ErrorKind::Unexpected { expected, found } => {
+                write!(f, "expected {}, found '{}'", expected, found)
+            }
+            ErrorKind::MissingMetadata(field) => {
+                write!(f, "cannot resolve '{}' from path or sheet name", field)
+            }
+            ErrorKind::NoMatchingProfile => {
+                write!(f, "no csv profile matches the path and column headers")
+            }
+        }
+    }
+}
+
+//error with the location in the library it originates from
+//file is filled in by the caller that knows which workbook is being read
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct IngestError {
+    pub file: String,
+    pub sheet: Option<String>,
+    pub cell: Option<(u32, u32)>,
+    pub kind: ErrorKind,
+}
+
+impl IngestError {
+    pub fn new(kind: ErrorKind) -> IngestError {
+        IngestError {
+            file: String::new(),
+            sheet: None,
+            cell: None,
+            kind,
+        }
+    }
+
+    pub fn unexpected(expected: &str, found: &str) -> IngestError {
+        IngestError::new(ErrorKind::Unexpected {
+            expected: expected.to_string(),
+            found: found.to_string(),
+        })
+    }
+
+    pub fn in_file(mut self, file: &str) -> IngestError {
+        if self.file.is_empty() {
+            self.file = file.to_string();
+        }
+        self
+    }
+
+    pub fn in_sheet(mut self, sheet: &str) -> IngestError {
+        if self.sheet.is_none() {
+            self.sheet = Some(sheet.to_string());
+        }
+        self
+    }
+
+    pub fn at(mut self, cell: (u32, u32)) -> IngestError {
+        if self.cell.is_none() {
+            self.cell = Some(cell);
+        }
+        self
+    }
+}
+
+//spreadsheet style coordinate, (row 0, column 0) is A1
+fn cell_name(cell: (u32, u32)) -> String {
+    let mut column = String::new();
+    let mut index = cell.1 + 1;
+    while index > 0 {
+        let rem = (index - 1) % 26;
+        column.insert(0, (b'A' + rem as u8) as char);
+        index = (index - 1) / 26;
+    }
+    format!("{}{}", column, cell.0 + 1)
+}
+
+impl std::fmt::Display for IngestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.file)?;
+        match (&self.sheet, self.cell) {
+            (Some(sheet), Some(cell)) => write!(f, " [{}!{}]", sheet, cell_name(cell))?,
+            (Some(sheet), None) => write!(f, " [{}]", sheet)?,
+            (None, Some(cell)) => write!(f, " [{}]", cell_name(cell))?,
+            (None, None) => (),
+        }
+        write!(f, ": {}", self.kind)
+    }
+}
+
+impl std::error::Error for IngestError {}
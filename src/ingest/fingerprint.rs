@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+//identifies the content of a scanned file, size and mtime are compared first so an unchanged
+//library is rescanned without reading every file
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Fingerprint {
+    pub size: u64,
+    //seconds since the unix epoch
+    pub modified: u64,
+    //sha256 of the content in hex
+    pub hash: String,
+}
+
+impl Fingerprint {
+    pub fn of(path: &Path) -> io::Result<Fingerprint> {
+        let (size, modified) = stat(path)?;
+        Ok(Fingerprint {
+            size,
+            modified,
+            hash: format!("{:x}", Sha256::digest(&fs::read(path)?)),
+        })
+    }
+
+    pub fn matches_stat(&self, size: u64, modified: u64) -> bool {
+        self.size == size && self.modified == modified
+    }
+}
+
+//size and modification time of a file
+pub fn stat(path: &Path) -> io::Result<(u64, u64)> {
+    let metadata = fs::metadata(path)?;
+    let modified = metadata
+        .modified()?
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0);
+    Ok((metadata.len(), modified))
+}
@@ -0,0 +1,36 @@
+use super::IngestError;
+use crate::measurement::metadata::MetadataParser;
+use crate::measurement::Measurement;
+
+//reads every measurement out of one file, chosen by the extension of the file
+//files are imported from several threads at once
+pub trait Importer: Send + Sync {
+    fn accepts(&self, extension: &str) -> bool;
+
+    fn import(&self, root: &str, relative_path: &str) -> Result<Vec<Measurement>, IngestError>;
+}
+
+//KITE .xls and Clarius .xlsx workbooks
+pub struct Workbook {
+    metadata: MetadataParser,
+}
+
+impl Importer for Workbook {
+    fn accepts(&self, extension: &str) -> bool {
+        matches!(extension, "xls" | "xlsx")
+    }
+
+    fn import(&self, root: &str, relative_path: &str) -> Result<Vec<Measurement>, IngestError> {
+        Measurement::extract(root, relative_path, &self.metadata)
+    }
+}
+
+pub fn importers(config: &crate::config::Config) -> Result<Vec<Box<dyn Importer>>, regex::Error> {
+    let metadata = MetadataParser::new(&config.metadata, &config.process_registry())?;
+    Ok(vec![
+        Box::new(Workbook {
+            metadata: metadata.clone(),
+        }),
+        Box::new(super::csv::Delimited::new(&config.csv_profiles, metadata)?),
+    ])
+}
@@ -0,0 +1,17 @@
+pub mod csv;
+
+pub mod error;
+
+pub mod fingerprint;
+
+pub mod importer;
+
+pub mod path;
+
+pub mod report;
+
+pub mod scan;
+
+pub use error::{ErrorKind, IngestError};
+pub use importer::Importer;
+pub use report::FileReport;
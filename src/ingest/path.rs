@@ -0,0 +1,32 @@
+use std::path::{Component, Path, PathBuf};
+
+//stored paths are relative to the library root and separated by '/' on every platform,
+//so a library scanned on windows can be opened on linux and the other way round
+pub fn normalize(path: &str) -> String {
+    path.split(&['/', '\\'][..])
+        .filter(|part| !part.is_empty() && *part != ".")
+        .collect::<Vec<&str>>()
+        .join("/")
+}
+
+//None when the path is not below the root
+pub fn relative(root: &Path, path: &Path) -> Option<String> {
+    let relative = path.strip_prefix(root).ok()?;
+    Some(
+        relative
+            .components()
+            .filter_map(|component| match component {
+                Component::Normal(part) => Some(part.to_string_lossy()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("/"),
+    )
+}
+
+//the file on this platform for a stored relative path
+pub fn absolute(root: &str, relative_path: &str) -> PathBuf {
+    normalize(relative_path)
+        .split('/')
+        .fold(PathBuf::from(root), |path, part| path.join(part))
+}
@@ -0,0 +1,96 @@
+use super::error::IngestError;
+use serde::{Deserialize, Serialize};
+
+//outcome of a single file during a scan
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "status")]
+pub enum FileStatus {
+    Ok { measurements: usize },
+    //a modified file that was read again
+    Updated {
+        added: usize,
+        updated: usize,
+        removed: usize,
+    },
+    Skipped { reason: String },
+    Failed { error: IngestError },
+    //a file of the library that no longer exists, its measurements are removed when purged
+    Vanished { measurements: usize, purged: bool },
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FileReport {
+    pub file: String,
+    pub status: FileStatus,
+}
+
+impl FileReport {
+    pub fn ok(file: &str, measurements: usize) -> FileReport {
+        FileReport {
+            file: file.to_string(),
+            status: FileStatus::Ok { measurements },
+        }
+    }
+
+    pub fn updated(file: &str, (added, updated, removed): (usize, usize, usize)) -> FileReport {
+        FileReport {
+            file: file.to_string(),
+            status: FileStatus::Updated {
+                added,
+                updated,
+                removed,
+            },
+        }
+    }
+
+    pub fn vanished(file: &str, measurements: usize, purged: bool) -> FileReport {
+        FileReport {
+            file: file.to_string(),
+            status: FileStatus::Vanished {
+                measurements,
+                purged,
+            },
+        }
+    }
+
+    pub fn skipped(file: &str, reason: &str) -> FileReport {
+        FileReport {
+            file: file.to_string(),
+            status: FileStatus::Skipped {
+                reason: reason.to_string(),
+            },
+        }
+    }
+
+    pub fn failed(file: &str, error: IngestError) -> FileReport {
+        FileReport {
+            file: file.to_string(),
+            status: FileStatus::Failed { error },
+        }
+    }
+
+    pub fn is_failed(&self) -> bool {
+        matches!(self.status, FileStatus::Failed { .. })
+    }
+
+    pub fn is_vanished(&self) -> bool {
+        matches!(self.status, FileStatus::Vanished { .. })
+    }
+
+    //(added, updated, removed) measurements
+    pub fn changes(&self) -> (usize, usize, usize) {
+        match self.status {
+            FileStatus::Ok { measurements } => (measurements, 0, 0),
+            FileStatus::Updated {
+                added,
+                updated,
+                removed,
+            } => (added, updated, removed),
+            FileStatus::Vanished {
+                measurements,
+                purged: true,
+            } => (0, 0, measurements),
+            _ => (0, 0, 0),
+        }
+    }
+}
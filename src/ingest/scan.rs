@@ -0,0 +1,398 @@
+use super::fingerprint::{self, Fingerprint};
+use super::{path, ErrorKind, FileReport, Importer, IngestError};
+use crate::database::{Database, ScannedFile};
+use crate::measurement::Measurement;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+//state of a running scan as shown in the window
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct Progress {
+    pub running: bool,
+    pub done: usize,
+    pub total: usize,
+    pub failed: usize,
+    //the file that was finished last
+    pub file: String,
+    pub last_error: Option<String>,
+}
+
+//what happened to one file during the parallel part of a scan
+enum Outcome {
+    Unsupported,
+    Unchanged(Fingerprint),
+    Unreadable(IngestError),
+    Imported {
+        fingerprint: Fingerprint,
+        known: bool,
+        result: Result<Vec<Measurement>, IngestError>,
+    },
+    //left alone because the scan was stopped
+    Stopped,
+}
+
+//directories that cannot be listed with the reason
+type Unreadable = Vec<(PathBuf, io::Error)>;
+
+//every file below the directory, subdirectories are read in parallel
+//directories that cannot be listed are returned with their error, the rest of the walk goes on
+fn walk(dir: &Path) -> (Vec<PathBuf>, Unreadable) {
+    let entries: Vec<PathBuf> = match fs::read_dir(dir).and_then(|entries| {
+        entries
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect()
+    }) {
+        Ok(entries) => entries,
+        Err(e) => return (vec![], vec![(dir.to_path_buf(), e)]),
+    };
+    let nested: Vec<(Vec<PathBuf>, Unreadable)> = entries
+        .into_par_iter()
+        .map(|path| {
+            if path.is_dir() {
+                walk(path.as_path())
+            } else {
+                (vec![path], vec![])
+            }
+        })
+        .collect();
+    nested.into_iter().fold(
+        (vec![], vec![]),
+        |(mut files, mut unreadable), (nested_files, nested_unreadable)| {
+            files.extend(nested_files);
+            unreadable.extend(nested_unreadable);
+            (files, unreadable)
+        },
+    )
+}
+
+//size and mtime first, the content only when they differ
+fn outcome(
+    known: &HashMap<&str, &ScannedFile>,
+    importers: &[Box<dyn Importer>],
+    root: &str,
+    file: &Path,
+    relative_path: &str,
+) -> Outcome {
+    let extension = file.extension().and_then(|ext| ext.to_str()).unwrap_or("");
+    let importer = match importers.iter().find(|i| i.accepts(extension)) {
+        Some(importer) => importer,
+        None => return Outcome::Unsupported,
+    };
+    let known = known.get(relative_path);
+    if let (Some(fingerprint), Ok((size, modified))) = (
+        known.and_then(|file| file.fingerprint.as_ref()),
+        fingerprint::stat(file),
+    ) {
+        if fingerprint.matches_stat(size, modified) {
+            return Outcome::Unchanged(fingerprint.clone());
+        }
+    }
+    let fingerprint = match Fingerprint::of(file) {
+        Ok(fingerprint) => fingerprint,
+        Err(e) => {
+            return Outcome::Unreadable(
+                IngestError::new(ErrorKind::Open(e.to_string())).in_file(relative_path),
+            )
+        }
+    };
+    //files of older libraries without a fingerprint are read once more, their runs keep their ids
+    if known
+        .and_then(|file| file.fingerprint.as_ref())
+        .is_some_and(|known| known.hash == fingerprint.hash)
+    {
+        return Outcome::Unchanged(fingerprint);
+    }
+    Outcome::Imported {
+        fingerprint,
+        known: known.is_some(),
+        result: importer.import(root, relative_path),
+    }
+}
+
+//the files of the library as read by scan, nothing is changed until it is applied
+pub struct Scan {
+    root: String,
+    files: Vec<(String, PathBuf)>,
+    unreadable: Vec<(String, io::Error)>,
+    outcomes: Vec<Outcome>,
+}
+
+//reads every new or modified file of the library, `known` are the files it holds
+//files are opened in parallel, progress is called after every file from the worker threads
+//once stop is set the remaining files are left as they are, e.g. when the window is closed
+pub fn scan(
+    root: &str,
+    known: &[ScannedFile],
+    importers: &[Box<dyn Importer>],
+    stop: &AtomicBool,
+    progress: &(dyn Fn(&Progress) + Sync),
+) -> Scan {
+    let root_path = Path::new(root);
+    let relative = |file: &Path| {
+        path::relative(root_path, file).unwrap_or_else(|| path::normalize(&file.to_string_lossy()))
+    };
+    let (files, unreadable) = if root_path.is_dir() {
+        walk(root_path)
+    } else {
+        (vec![], vec![])
+    };
+    let mut files: Vec<(String, PathBuf)> = files
+        .into_iter()
+        .map(|file| (relative(file.as_path()), file))
+        .collect();
+    let unreadable: Vec<(String, io::Error)> = unreadable
+        .into_iter()
+        .map(|(dir, e)| (relative(dir.as_path()), e))
+        .collect();
+    files.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+    let total = files.len();
+    let done = AtomicUsize::new(0);
+    let failed = AtomicUsize::new(0);
+    let last_error: Mutex<Option<String>> = Mutex::new(None);
+    let outcomes: Vec<Outcome> = {
+        let known: HashMap<&str, &ScannedFile> = known
+            .iter()
+            .map(|file| (file.path.as_str(), file))
+            .collect();
+        files
+            .par_iter()
+            .map(|(relative_path, file)| {
+                if stop.load(Ordering::SeqCst) {
+                    return Outcome::Stopped;
+                }
+                let outcome = outcome(&known, importers, root, file, relative_path);
+                let error = match &outcome {
+                    Outcome::Unreadable(error)
+                    | Outcome::Imported {
+                        result: Err(error), ..
+                    } => Some(error.to_string()),
+                    _ => None,
+                };
+                let failed = match error {
+                    Some(error) => {
+                        *last_error.lock().unwrap() = Some(error);
+                        failed.fetch_add(1, Ordering::SeqCst) + 1
+                    }
+                    None => failed.load(Ordering::SeqCst),
+                };
+                progress(&Progress {
+                    running: true,
+                    done: done.fetch_add(1, Ordering::SeqCst) + 1,
+                    total,
+                    failed,
+                    file: relative_path.clone(),
+                    last_error: last_error.lock().unwrap().clone(),
+                });
+                outcome
+            })
+            .collect()
+    };
+    Scan {
+        root: root.to_string(),
+        files,
+        unreadable,
+        outcomes,
+    }
+}
+
+impl Scan {
+    //merges the files in the order of their path, so ids and aliases do not depend on the thread
+    //scheduling, files of the library that were not found are reported and removed when purged
+    pub fn apply(self, database: &mut Database, purge: bool) -> Vec<FileReport> {
+        let Scan {
+            root,
+            files,
+            unreadable,
+            outcomes,
+        } = self;
+        let mut report = vec![];
+        for (relative_dir, e) in unreadable.iter() {
+            //the root itself has an empty relative path
+            let dir = if relative_dir.is_empty() {
+                root.as_str()
+            } else {
+                relative_dir
+            };
+            let error = IngestError::new(ErrorKind::Directory(e.to_string())).in_file(dir);
+            println!("{}", error);
+            report.push(FileReport::failed(dir, error));
+        }
+        for ((relative_path, _), outcome) in files.iter().zip(outcomes) {
+            let relative_path = relative_path.as_str();
+            match outcome {
+                Outcome::Stopped => {}
+                Outcome::Unsupported => {
+                    report.push(FileReport::skipped(relative_path, "unsupported file type"))
+                }
+                Outcome::Unchanged(fingerprint) => {
+                    database.scanned(relative_path, fingerprint);
+                    report.push(FileReport::skipped(relative_path, "unchanged"));
+                }
+                Outcome::Unreadable(error) => {
+                    println!("{}", error);
+                    report.push(FileReport::failed(relative_path, error));
+                }
+                Outcome::Imported {
+                    fingerprint,
+                    known,
+                    result: Ok(measurements),
+                } => {
+                    if known {
+                        let changes = database.replace(relative_path, measurements);
+                        report.push(FileReport::updated(relative_path, changes));
+                    } else {
+                        let amount = database.insert(measurements);
+                        report.push(FileReport::ok(relative_path, amount));
+                    }
+                    database.scanned(relative_path, fingerprint);
+                }
+                //a modified file that fails keeps its previous measurements
+                Outcome::Imported {
+                    result: Err(error), ..
+                } => {
+                    println!("{}", error);
+                    report.push(FileReport::failed(relative_path, error));
+                }
+            }
+        }
+
+        //the files below a directory that cannot be listed are not taken as vanished
+        let hidden: Vec<String> = database
+            .files
+            .iter()
+            .map(|file| file.path.clone())
+            .filter(|file| {
+                unreadable
+                    .iter()
+                    .any(|(dir, _)| dir.is_empty() || file.starts_with(&format!("{}/", dir)))
+            })
+            .collect();
+        let seen: HashSet<&str> = files
+            .iter()
+            .map(|(relative_path, _)| relative_path.as_str())
+            .chain(hidden.iter().map(|file| file.as_str()))
+            .collect();
+        report.extend(database.vanished(&seen, purge));
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::ingest::report::FileStatus;
+
+    const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/csv");
+
+    fn rescan(
+        database: &mut Database,
+        importers: &[Box<dyn Importer>],
+        purge: bool,
+    ) -> Vec<FileReport> {
+        let known = database.files.clone();
+        scan(
+            FIXTURES,
+            &known,
+            importers,
+            &AtomicBool::new(false),
+            &|_| {},
+        )
+        .apply(database, purge)
+    }
+
+    fn importers() -> Vec<Box<dyn Importer>> {
+        let config = Config::load(concat!(env!("CARGO_MANIFEST_DIR"), "/config.json")).unwrap();
+        crate::ingest::importer::importers(&config).unwrap()
+    }
+
+    //a library migrated from layout 1 lists its files without a fingerprint
+    #[test]
+    fn files_without_fingerprint_are_read_again() {
+        let importers = importers();
+        let mut database = Database::new();
+        let report = rescan(&mut database, &importers, false);
+        assert!(matches!(
+            report[0].status,
+            FileStatus::Ok { measurements: 1 }
+        ));
+        let id = database.measurements[0].id.clone();
+
+        database.files[0].fingerprint = None;
+        let report = rescan(&mut database, &importers, false);
+        assert!(
+            matches!(
+                report[0].status,
+                FileStatus::Updated {
+                    added: 0,
+                    updated: 1,
+                    removed: 0
+                }
+            ),
+            "{:?}",
+            report
+        );
+        assert_eq!(database.measurements.len(), 1);
+        assert_eq!(database.measurements[0].id, id);
+        assert!(database.files[0].fingerprint.is_some());
+
+        let report = rescan(&mut database, &importers, false);
+        assert!(matches!(report[0].status, FileStatus::Skipped { .. }));
+    }
+
+    #[test]
+    fn files_that_are_gone_are_flagged_or_purged() {
+        let importers = importers();
+        let mut database = Database::new();
+        rescan(&mut database, &importers, false);
+        let mut gone = database.measurements[0].clone();
+        gone.id = "gone".to_string();
+        gone.file_path = "gone.csv".to_string();
+        database.measurements.push(gone);
+        database.files.push(ScannedFile {
+            path: "gone.csv".to_string(),
+            fingerprint: None,
+            vanished: false,
+        });
+        let vanished = |report: &[FileReport]| {
+            report
+                .iter()
+                .filter(|report| report.is_vanished())
+                .map(|report| (report.file.clone(), report.status.clone()))
+                .collect::<Vec<_>>()
+        };
+
+        let report = rescan(&mut database, &importers, false);
+        let flagged = vanished(&report);
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(flagged[0].0, "gone.csv");
+        assert!(matches!(
+            flagged[0].1,
+            FileStatus::Vanished {
+                measurements: 1,
+                purged: false
+            }
+        ));
+        assert!(database.file("gone.csv").unwrap().vanished);
+        assert_eq!(database.measurements.len(), 2);
+
+        let report = rescan(&mut database, &importers, true);
+        assert!(matches!(
+            vanished(&report)[0].1,
+            FileStatus::Vanished {
+                measurements: 1,
+                purged: true
+            }
+        ));
+        assert!(database.file("gone.csv").is_none());
+        assert_eq!(database.measurements.len(), 1);
+        assert_eq!(database.discarded, vec!["gone"]);
+    }
+}
@@ -1,48 +1,38 @@
 use crate::database::Database;
+use boolinator::Boolinator;
 use clap::{App, Arg};
-use std::fs::{self};
+use notify::{DebouncedEvent, RecursiveMode, Watcher};
+use std::fs;
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
 use webview::*;
 
 mod measurement;
 
 mod calamine_helper;
 
+mod config;
+
 mod database;
 
 mod elm;
 
+mod ingest;
+
+mod query;
+
+mod store;
+
 pub trait Extract {
-    fn extract(sheet: &calamine_helper::MyRange) -> Option<Self>
+    fn extract(settings: &measurement::settings::Section) -> Result<Self, ingest::IngestError>
     where
         Self: std::marker::Sized;
 }
 
-fn populate_from_path(
-    root: String,
-    relative_dir: String,
-    storage: &mut database::Database,
-) -> std::io::Result<()> {
-    let dir_string = format!("{}{}", root.clone(), relative_dir);
-
-    let dir = Path::new(dir_string.as_str());
-
-    if dir.is_dir() {
-        for entry in fs::read_dir(dir)? {
-            let entry = entry?;
-            let path = entry.path();
-            let relative_path: String = path.to_str().unwrap().replace(root.clone().as_str(), "");
-            if path.is_dir() {
-                populate_from_path(root.clone(), relative_path, storage)?;
-            } else if path.extension().unwrap() == "xls" {
-                measurement::Measurement::extract(root.clone(), relative_path, storage);
-            } else {
-            }
-        }
-    }
-    Ok(())
-}
-
 fn main() {
     let matches = App::new("Library tool")
         .version("0.1")
@@ -71,6 +61,64 @@ fn main() {
                 .value_name("PATH")
                 .help("Sets the directory where the python scripts selected out of"),
         )
+        .arg(
+            Arg::with_name("config")
+                .short("c")
+                .long("config")
+                .value_name("FILE")
+                .help("Sets the json file with the import profiles"),
+        )
+        .arg(
+            Arg::with_name("purge")
+                .long("purge")
+                .help("Removes the measurements of files that no longer exist in the input directory"),
+        )
+        .arg(
+            Arg::with_name("watch")
+                .short("w")
+                .long("watch")
+                .value_name("SECONDS")
+                .min_values(0)
+                .help("Keeps importing new and changed files, a file is read once it was not written to for SECONDS (default 5)"),
+        )
+        .arg(
+            Arg::with_name("convert")
+                .long("convert")
+                .help("Rewrites the samples of the library in the output directory with the encoding of the config and exits"),
+        )
+        .arg(
+            Arg::with_name("query")
+                .long("query")
+                .value_name("QUERY")
+                .help("Lists the runs of the library matching QUERY, e.g. \"wafer in (GF22, MINOXG) and W >= 1um\", and exits"),
+        )
+        .arg(
+            Arg::with_name("merge")
+                .long("merge")
+                .value_name("PATH")
+                .help("Adds the runs of another library, a directory or an archive written by --extract, to the library in the output directory and exits"),
+        )
+        .arg(
+            Arg::with_name("extract")
+                .long("extract")
+                .value_name("FILE")
+                .requires("ids")
+                .help("Writes the runs given with --ids as a library of its own into the archive FILE and exits"),
+        )
+        .arg(
+            Arg::with_name("ids")
+                .long("ids")
+                .value_name("ID")
+                .multiple(true)
+                .use_delimiter(true)
+                .help("Ids or aliases of the runs to extract"),
+        )
+        .arg(
+            Arg::with_name("with_sources")
+                .long("with_sources")
+                .requires("extract")
+                .help("Adds the workbooks the extracted runs were read from to the archive"),
+        )
         .get_matches();
 
     //default input directory
@@ -97,19 +145,120 @@ fn main() {
         .unwrap_or(script_string.as_str())
         .to_string();
 
-    //initialize id and measurement vector
-    let json = fs::read_to_string(format!("{}/result.json", output_dir));
-    let storage: &mut Database = &mut Database::new();
-    match json {
-        Ok(string) => {
-            *storage = serde_json::from_str::<Database>(string.as_str()).unwrap_or(Database::new())
+    //default config file
+    let config_string: String = format!("{}/config.json", env!("CARGO_MANIFEST_DIR"));
+    // get config file from CLI
+    let config_path = matches
+        .value_of("config")
+        .unwrap_or(config_string.as_str())
+        .to_string();
+    let config = config::Config::load(config_path.as_str()).expect("Error reading config");
+    let importers = ingest::importer::importers(&config).expect("Invalid pattern in config");
+    let processes = config.process_registry();
+
+    let store = Arc::new(
+        store::Store::new(output_dir.as_str(), &config.store).unwrap_or_else(|e| {
+            eprintln!("Cannot open the library in {}: {}", output_dir, e);
+            std::process::exit(1)
+        }),
+    );
+
+    //a library that cannot be read stops the tool instead of being replaced by an empty one
+    let (mut storage, version) = match store.load_index() {
+        Ok(Some(loaded)) => loaded,
+        Ok(None) => (Database::new(), database::migration::SCHEMA_VERSION),
+        Err(e) => {
+            eprintln!("Cannot read the library {}: {}", store.index_path().display(), e);
+            match store.backup() {
+                Ok(backup) => eprintln!("A copy of it is kept as {}", backup.display()),
+                Err(e) => eprintln!("No copy could be made: {}", e),
+            }
+            std::process::exit(1)
         }
-        _ => (),
     };
-    populate_from_path(input_dir, String::from(""), storage).expect("Error transfercing path");
+    if version < database::migration::SCHEMA_VERSION {
+        let backup = store.backup().expect("error writing the backup");
+        println!(
+            "Migrating the library from schema {} to {}, the old file is kept as {}",
+            version,
+            database::migration::SCHEMA_VERSION,
+            backup.display()
+        );
+    }
+    storage.resolve_processes(&processes);
+    if matches.is_present("convert") {
+        let started = Instant::now();
+        let (before, after) = store
+            .convert(&mut storage)
+            .expect("error converting the library");
+        println!(
+            "Converted {} runs in {:.1} s: {:.1} MB -> {:.1} MB",
+            storage.measurements.len(),
+            started.elapsed().as_secs_f64(),
+            before as f64 / 1e6,
+            after as f64 / 1e6
+        );
+        return;
+    }
+    //samples of older libraries are moved out of result.json, an imported library is written
+    if version < database::migration::SCHEMA_VERSION || !storage.changed.is_empty() {
+        store.save(&mut storage).expect("error writing the store");
+    }
+    if let Some(text) = matches.value_of("query") {
+        let query = query::Query::parse(text).unwrap_or_else(|e| {
+            eprintln!("{}", e.show(text));
+            std::process::exit(1)
+        });
+        let measurements = store.query(&storage, &query);
+        for m in measurements.iter() {
+            println!("{}\t{}\t{}\t{}", m.id, m.alias, m.file_path, m.sheet_name);
+        }
+        println!("{} of {} runs match", measurements.len(), storage.measurements.len());
+        return;
+    }
+    if let Some(source) = matches.value_of("merge") {
+        merge(&store, &mut storage, source, &config, &processes).unwrap_or_else(|e| {
+            eprintln!("Cannot merge {}: {}", source, e);
+            std::process::exit(1)
+        });
+        return;
+    }
+    if let Some(archive) = matches.value_of("extract") {
+        let ids: Vec<&str> = matches.values_of("ids").into_iter().flatten().collect();
+        let sources = matches
+            .is_present("with_sources")
+            .as_some(input_dir.as_str());
+        extract(&store, &storage, archive, &ids, sources).unwrap_or_else(|e| {
+            eprintln!("Cannot extract {}: {}", archive, e);
+            std::process::exit(1)
+        });
+        return;
+    }
 
-    let v = serde_json::to_string(storage).unwrap();
-    fs::write(format!("{}/result.json", output_dir), v.as_str()).expect("error writing json");
+    //the window opens with the library of the last run, the scan runs in the background
+    let initial_state = {
+        let message_nr = 0;
+        let task_done = elm::Task::Init;
+        let filter_options = store.options(&storage, None);
+        let measurements: Vec<measurement::MeasurementCompact> =
+            store.filter(&storage, &elm::filter::FilterQuery::from(filter_options));
+        let filter_options = elm::filter::FilterOptions::new(&measurements);
+        let ingest_report = storage.ingest_report.clone();
+        elm::ToElm {
+            message_nr,
+            task_done,
+            measurements,
+            filter_options,
+            ingest_report,
+            scan: Default::default(),
+            query_error: None,
+            saved: storage.saved.clone(),
+            saved_error: None,
+        }
+    };
+    let storage: Arc<Mutex<Database>> = Arc::new(Mutex::new(storage));
+    //the query of the last filtering, so a finished scan does not reset the filters of the user
+    let last_query: Arc<Mutex<Option<elm::Selection>>> = Arc::new(Mutex::new(None));
 
     let html = format!(
         r#"<!doctype html>
@@ -132,7 +281,7 @@ fn main() {
         </html>
 		"#,
         styles = inline_style(include_str!("../elm-code/styles.css")),
-        scripts = inline_script(include_str!("../elm-code/elm.js"))
+        scripts = inline_script(include_str!(concat!(env!("OUT_DIR"), "/elm.js")))
             + &inline_script(include_str!("../elm-code/app.js")),
     );
 
@@ -142,95 +291,165 @@ fn main() {
         .size(1600, 900)
         .resizable(true)
         .debug(true)
-        .user_data({
-            let message_nr = 0;
-            let task_done = elm::Task::Init;
-            let measurements: Vec<measurement::MeasurementCompact> = storage
-                .measurements
-                .clone()
-                .into_iter()
-                .map(|m| m.to_compact())
-                .collect();
-            let filter_options = elm::filter::FilterOptions::new(&measurements);
-            let measurements: Vec<measurement::MeasurementCompact> =
-                elm::filter::FilterQuery::from(filter_options).filter(measurements);
-            let filter_options = elm::filter::FilterOptions::new(&measurements);
-            let result = elm::ToElm {
-                message_nr,
-                task_done,
-                measurements,
-                filter_options,
-            };
-            //println!("{}", serde_json::to_string_pretty(&result).unwrap());
-            result
-        })
+        .user_data(initial_state)
         .invoke_handler(|webview, arg| {
-            let compact_msmt: Vec<measurement::MeasurementCompact> = storage
-                .measurements
-                .clone()
-                .into_iter()
-                .map(|m| m.to_compact())
-                .collect();
+            let mut storage = storage.lock().unwrap();
             let to_elm = webview.user_data_mut();
             if serde_json::from_str::<elm::FromElm>(arg).is_err() {
                 println!("{:#?}", arg);
             }
             match serde_json::from_str(arg).unwrap() {
                 elm::FromElm::Init => {
+                    *last_query.lock().unwrap() = None;
                     *to_elm = {
                         println!("Init {}", to_elm.message_nr);
                         let message_nr = to_elm.message_nr + 1;
                         let task_done = elm::Task::Init;
-                        let measurements = compact_msmt.clone();
-                        let filter_options = elm::filter::FilterOptions::new(&measurements);
-                        let measurements: Vec<measurement::MeasurementCompact> =
-                            elm::filter::FilterQuery::from(filter_options.clone())
-                                .filter(measurements);
+                        let filter_options = store.options(&storage, None);
+                        let measurements: Vec<measurement::MeasurementCompact> = store.filter(
+                            &storage,
+                            &elm::filter::FilterQuery::from(filter_options.clone()),
+                        );
+                        let ingest_report = storage.ingest_report.clone();
+                        let scan = to_elm.scan.clone();
                         elm::ToElm {
                             message_nr,
                             task_done,
                             measurements,
                             filter_options,
+                            ingest_report,
+                            scan,
+                            query_error: None,
+                            saved: storage.saved.clone(),
+                            saved_error: None,
                         }
                     }
                 }
                 elm::FromElm::Log(string) => println!("{}", string),
                 elm::FromElm::Filter(query) => {
+                    *last_query.lock().unwrap() = Some(elm::Selection::Filter(query.clone()));
                     *to_elm = {
                         let message_nr = to_elm.message_nr + 1;
                         println!("Filtering");
                         let task_done = elm::Task::Filtering;
-                        let measurements = query.filter(compact_msmt.clone());
-                        let filter_options =
-                            elm::filter::FilterOptions::filtered(&compact_msmt, query);
+                        let measurements = store.filter(&storage, &query);
+                        let filter_options = store.options(&storage, Some(&query));
+                        let ingest_report = to_elm.ingest_report.clone();
+                        let scan = to_elm.scan.clone();
                         elm::ToElm {
                             message_nr,
                             task_done,
                             measurements,
                             filter_options,
+                            ingest_report,
+                            scan,
+                            query_error: None,
+                            saved: storage.saved.clone(),
+                            saved_error: None,
+                        }
+                    }
+                }
+                elm::FromElm::Query(text) => {
+                    println!("Querying {}", text);
+                    let message_nr = to_elm.message_nr + 1;
+                    match query::Query::parse(text.as_str()) {
+                        Ok(query) => {
+                            let measurements = store.query(&storage, &query);
+                            let filter_options = elm::filter::FilterOptions::new(&measurements);
+                            *last_query.lock().unwrap() = Some(elm::Selection::Query(query));
+                            to_elm.measurements = measurements;
+                            to_elm.filter_options = filter_options;
+                            to_elm.query_error = None;
+                        }
+                        //the runs of the last selection stay
+                        Err(e) => {
+                            println!("{}", e.show(text.as_str()));
+                            to_elm.query_error = Some(e);
                         }
                     }
+                    to_elm.message_nr = message_nr;
+                    to_elm.task_done = elm::Task::Filtering;
                 }
-                elm::FromElm::Process(query) => {
+                elm::FromElm::Process(mut query) => {
+                    if let Some(name) = query.collection() {
+                        match storage.collection(name) {
+                            Some(runs) => query.add_runs(&runs),
+                            None => println!("There is no collection named {}", name),
+                        }
+                    }
                     *to_elm = {
                         println!("Processing");
                         let message_nr = to_elm.message_nr + 1;
-                        query.process(
-                            storage.measurements.clone(),
-                            output_dir.as_str(),
-                            script_dir.as_str(),
-                        );
+                        //only the samples of the selected runs are read
+                        let ids = query.ids();
+                        match storage
+                            .measurements
+                            .iter()
+                            .filter(|m| ids.contains(&m.id))
+                            .map(|m| store.load(m))
+                            .collect::<std::io::Result<Vec<measurement::Measurement>>>()
+                        {
+                            Ok(measurements) => query.process(
+                                measurements,
+                                &processes,
+                                output_dir.as_str(),
+                                script_dir.as_str(),
+                            ),
+                            Err(e) => println!("Cannot process: {}", e),
+                        }
                         let task_done = elm::Task::Processing;
                         let measurements = to_elm.measurements.clone();
                         let filter_options = to_elm.filter_options.clone();
+                        let ingest_report = to_elm.ingest_report.clone();
+                        let scan = to_elm.scan.clone();
+                        let query_error = to_elm.query_error.clone();
+                        let saved_error = to_elm.saved_error.clone();
                         elm::ToElm {
                             message_nr,
                             task_done,
                             measurements,
                             filter_options,
+                            ingest_report,
+                            scan,
+                            query_error,
+                            saved: storage.saved.clone(),
+                            saved_error,
                         }
                     }
                 }
+                elm::FromElm::SaveQuery { name, query } => {
+                    let result = storage.save_query(name.as_str(), query);
+                    keep_saved(result, &mut storage, &store, to_elm);
+                }
+                elm::FromElm::SaveCollection { name, ids } => {
+                    let result = storage.save_collection(name.as_str(), &ids);
+                    keep_saved(result, &mut storage, &store, to_elm);
+                }
+                elm::FromElm::Rename { kind, name, to } => {
+                    let result = storage.rename_saved(kind, name.as_str(), to.as_str());
+                    keep_saved(result, &mut storage, &store, to_elm);
+                }
+                elm::FromElm::Delete { kind, name } => {
+                    let result = storage.delete_saved(kind, name.as_str());
+                    keep_saved(result, &mut storage, &store, to_elm);
+                }
+                elm::FromElm::Collection(name) => {
+                    match storage.collection(name.as_str()) {
+                        Some(measurements) => {
+                            to_elm.filter_options = elm::filter::FilterOptions::new(&measurements);
+                            to_elm.measurements = measurements;
+                            to_elm.query_error = None;
+                            to_elm.saved_error = None;
+                            *last_query.lock().unwrap() = Some(elm::Selection::Collection(name));
+                        }
+                        None => {
+                            to_elm.saved_error =
+                                Some(format!("There is no collection named {}", name))
+                        }
+                    }
+                    to_elm.message_nr += 1;
+                    to_elm.task_done = elm::Task::Filtering;
+                }
             }
 
             render(webview)
@@ -240,11 +459,266 @@ fn main() {
 
     webview.set_color((255, 255, 255));
 
+    //seconds a file has to stay untouched before it is imported in watch mode
+    let watch = if matches.is_present("watch") {
+        let seconds = matches
+            .value_of("watch")
+            .map(|seconds| seconds.parse::<f64>().expect("invalid watch delay"))
+            .unwrap_or(5.0);
+        Some(Duration::from_secs_f64(seconds))
+    } else {
+        None
+    };
+    let closed = Arc::new(AtomicBool::new(false));
+    let scanner = scan_in_background(
+        webview.handle(),
+        Arc::clone(&storage),
+        Arc::clone(&last_query),
+        Arc::clone(&store),
+        importers,
+        input_dir.clone(),
+        matches.is_present("purge"),
+        watch,
+        Arc::clone(&closed),
+    );
+
     let res = webview.run().expect("Error in webview part");
+    closed.store(true, Ordering::SeqCst);
+    if scanner.join().is_err() {
+        println!("The library scan stopped with an error");
+    }
 
     println!("final state: {:?}", res);
 }
 
+//scans the library on a worker thread, the window gets the progress a few times per second and
+//the new measurements and filter options once the scan is done
+//with a watch delay the input directory is watched afterwards and scanned again whenever files
+//were created, written or removed, a file counts as written once it was left alone for the delay
+#[allow(clippy::too_many_arguments)]
+fn scan_in_background(
+    handle: Handle<elm::ToElm>,
+    storage: Arc<Mutex<Database>>,
+    last_query: Arc<Mutex<Option<elm::Selection>>>,
+    store: Arc<store::Store>,
+    importers: Vec<Box<dyn ingest::Importer>>,
+    input_dir: String,
+    purge: bool,
+    watch: Option<Duration>,
+    closed: Arc<AtomicBool>,
+) -> thread::JoinHandle<()> {
+    thread::spawn(move || {
+        //a scan that fails is reported and the directory is watched further
+        let scan_once = || {
+            if let Err(e) = rescan(
+                &handle,
+                &storage,
+                &last_query,
+                &store,
+                &importers,
+                input_dir.as_str(),
+                purge,
+                &closed,
+            ) {
+                println!("{}", e);
+            }
+        };
+        scan_once();
+        let delay = match watch {
+            Some(delay) => delay,
+            None => return,
+        };
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = match notify::watcher(tx, delay) {
+            Ok(watcher) => watcher,
+            Err(e) => return println!("Cannot watch {}: {}", input_dir, e),
+        };
+        if let Err(e) = watcher.watch(input_dir.as_str(), RecursiveMode::Recursive) {
+            return println!("Cannot watch {}: {}", input_dir, e);
+        }
+        println!("Watching {} for new measurements", input_dir);
+        while !closed.load(Ordering::SeqCst) {
+            match rx.recv_timeout(Duration::from_millis(500)) {
+                Ok(DebouncedEvent::Create(_))
+                | Ok(DebouncedEvent::Write(_))
+                | Ok(DebouncedEvent::Remove(_))
+                | Ok(DebouncedEvent::Rename(_, _))
+                | Ok(DebouncedEvent::Rescan) => {
+                    //events that arrived together are handled by one scan
+                    while rx.try_recv().is_ok() {}
+                    scan_once();
+                }
+                Ok(DebouncedEvent::Error(e, path)) => println!("Watch error {:?}: {}", path, e),
+                Ok(_) | Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+        }
+    })
+}
+
+//one scan of the library, the result is written to result.json and sent to the window
+//the filter of the user is kept, the new measurements are shown as far as they match it
+//a scan stopped by closing the window is dropped
+#[allow(clippy::too_many_arguments)]
+fn rescan(
+    handle: &Handle<elm::ToElm>,
+    storage: &Mutex<Database>,
+    last_query: &Mutex<Option<elm::Selection>>,
+    store: &store::Store,
+    importers: &[Box<dyn ingest::Importer>],
+    input_dir: &str,
+    purge: bool,
+    closed: &AtomicBool,
+) -> Result<(), String> {
+    //the window keeps working with the library while the files are read
+    let known = storage.lock().unwrap().files.clone();
+    let last_update = Mutex::new(Instant::now());
+    let scan = ingest::scan::scan(
+        input_dir,
+        &known,
+        importers,
+        closed,
+        &|progress| {
+            //the whole state is sent with every update
+            let mut last_update = last_update.lock().unwrap();
+            if last_update.elapsed() < Duration::from_millis(250) {
+                return;
+            }
+            *last_update = Instant::now();
+            let progress = progress.clone();
+            //fails only when the window is closed already
+            let _ = handle.dispatch(move |webview| {
+                let to_elm = webview.user_data_mut();
+                to_elm.message_nr += 1;
+                to_elm.task_done = elm::Task::Scanning;
+                to_elm.scan = progress;
+                render(webview)
+            });
+        },
+    );
+    if closed.load(Ordering::SeqCst) {
+        return Ok(());
+    }
+    let mut current = storage.lock().unwrap();
+    let report = scan.apply(&mut current, purge);
+    let (added, updated, removed) = report.iter().map(|r| r.changes()).fold(
+        (0, 0, 0),
+        |(added, updated, removed), (a, u, r)| (added + a, updated + u, removed + r),
+    );
+    let vanished = report.iter().filter(|r| r.is_vanished()).count();
+    let failed = report.iter().filter(|r| r.is_failed()).count();
+    println!(
+        "Scanned {} files, {} failed, {} vanished: {} measurements added, {} updated, {} removed",
+        report.len() - vanished,
+        failed,
+        vanished,
+        added,
+        updated,
+        removed
+    );
+    current.ingest_report = report;
+    //the samples and the index are written without holding the library
+    let save = store.prepare(&mut current);
+    drop(current);
+    let written = save.and_then(|save| save.write());
+    let mut current = storage.lock().unwrap();
+    match written {
+        Ok(written) => current.changed.retain(|id| !written.contains(id)),
+        Err(e) => {
+            drop(current);
+            let error = format!("Cannot write {}: {}", store.index_path().display(), e);
+            let last_error = Some(error.clone());
+            let _ = handle.dispatch(move |webview| {
+                let to_elm = webview.user_data_mut();
+                to_elm.message_nr += 1;
+                to_elm.task_done = elm::Task::Scanning;
+                to_elm.scan.running = false;
+                to_elm.scan.last_error = last_error;
+                render(webview)
+            });
+            return Err(error);
+        }
+    }
+
+    let ingest_report = current.ingest_report.clone();
+    let scan = ingest::scan::Progress {
+        running: false,
+        done: ingest_report.len() - vanished,
+        total: ingest_report.len() - vanished,
+        failed,
+        file: String::new(),
+        last_error: None,
+    };
+    let (task_done, measurements, filter_options) = match last_query.lock().unwrap().clone() {
+        Some(elm::Selection::Filter(query)) => (
+            elm::Task::Scanned,
+            store.filter(&current, &query),
+            store.options(&current, Some(&query)),
+        ),
+        Some(elm::Selection::Query(query)) => {
+            let measurements = store.query(&current, &query);
+            let filter_options = elm::filter::FilterOptions::new(&measurements);
+            (elm::Task::Scanned, measurements, filter_options)
+        }
+        Some(elm::Selection::Collection(name)) => {
+            let measurements = current.collection(name.as_str()).unwrap_or_default();
+            let filter_options = elm::filter::FilterOptions::new(&measurements);
+            (elm::Task::Scanned, measurements, filter_options)
+        }
+        //without a filter of the user the window is initialized again
+        None => {
+            let filter_options = store.options(&current, None);
+            (
+                elm::Task::Init,
+                store.filter(&current, &elm::filter::FilterQuery::from(filter_options.clone())),
+                filter_options,
+            )
+        }
+    };
+    let saved = current.saved.clone();
+    drop(current);
+    let _ = handle.dispatch(move |webview| {
+        let to_elm = webview.user_data_mut();
+        *to_elm = elm::ToElm {
+            message_nr: to_elm.message_nr + 1,
+            task_done,
+            measurements,
+            filter_options,
+            ingest_report,
+            scan,
+            query_error: None,
+            saved,
+            saved_error: to_elm.saved_error.clone(),
+        };
+        render(webview)
+    });
+    Ok(())
+}
+
+//a saved query or collection that changed is written with the library and sent to the window,
+//the window shows why it could not be changed or written
+fn keep_saved(
+    result: Result<(), String>,
+    storage: &mut Database,
+    store: &store::Store,
+    to_elm: &mut elm::ToElm,
+) {
+    to_elm.saved_error = match result.and_then(|_| {
+        store
+            .save(storage)
+            .map_err(|e| format!("Cannot write {}: {}", store.index_path().display(), e))
+    }) {
+        Ok(()) => None,
+        Err(e) => {
+            println!("{}", e);
+            Some(e)
+        }
+    };
+    to_elm.message_nr += 1;
+    to_elm.task_done = elm::Task::Saved;
+    to_elm.saved = storage.saved.clone();
+}
+
 fn render(webview: &mut WebView<elm::ToElm>) -> WVResult {
     let render_tasks = {
         let to_elm = webview.user_data();
@@ -263,3 +737,100 @@ fn inline_style(s: &str) -> String {
 fn inline_script(s: &str) -> String {
     format!(r#"<script type="text/javascript">{}</script>"#, s)
 }
+
+//adds the runs of the library in `source`, a directory or an archive, to the library
+fn merge(
+    store: &store::Store,
+    storage: &mut Database,
+    source: &str,
+    config: &config::Config,
+    processes: &measurement::process::ProcessRegistry,
+) -> Result<(), String> {
+    let source = Path::new(source);
+    let unpacked = if source.is_file() {
+        let dir = store.dir().join(".merge");
+        //left over when an earlier merge failed
+        match fs::remove_dir_all(&dir) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e.to_string()),
+            _ => {}
+        }
+        store::archive::unpack(source, &dir).map_err(|e| e.to_string())?;
+        Some(dir)
+    } else {
+        None
+    };
+    let dir = unpacked.as_deref().unwrap_or(source);
+    let other_store = store::Store::existing(dir, &config.store)?;
+    let (mut other, _) = other_store
+        .load_index()?
+        .ok_or_else(|| format!("no library in {}", dir.display()))?;
+    other.resolve_processes(processes);
+    if store.index_path().exists() {
+        let backup = store.backup().map_err(|e| e.to_string())?;
+        println!("The library before the merge is kept as {}", backup.display());
+    }
+    let report = storage
+        .merge(other, &|m| {
+            other_store.test_data(m).map(|test_data| test_data.as_ref().clone())
+        })
+        .map_err(|e| e.to_string())?;
+    store.save(storage).map_err(|e| e.to_string())?;
+    if let Some(dir) = unpacked {
+        fs::remove_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    println!(
+        "Merged {}: {} runs added, {} already in the library, {} files added",
+        source.display(),
+        report.added,
+        report.duplicates,
+        report.files_added
+    );
+    for (old, new) in report.renamed.iter() {
+        println!("{} was taken, the run is added as {}", old, new);
+    }
+    for path in report.conflicting_files.iter() {
+        println!("{} differs between the libraries, the runs of this library are kept", path);
+    }
+    if report.conflicting > 0 {
+        println!("{} runs of differing files were left out", report.conflicting);
+    }
+    for entry in report.saved_left_out.iter() {
+        println!("The saved {} was left out", entry);
+    }
+    if report.collection_runs_left_out > 0 {
+        println!(
+            "{} runs of saved collections were not merged and left out of them",
+            report.collection_runs_left_out
+        );
+    }
+    Ok(())
+}
+
+//writes the given runs as a library of its own into an archive
+fn extract(
+    store: &store::Store,
+    storage: &Database,
+    archive: &str,
+    names: &[&str],
+    sources: Option<&str>,
+) -> Result<(), String> {
+    let ids = names
+        .iter()
+        .map(|name| {
+            storage
+                .resolve_id(name)
+                .ok_or_else(|| format!("no run {} in the library", name))
+        })
+        .collect::<Result<Vec<String>, String>>()?;
+    let extracted = storage.extract(&ids);
+    let added = store::archive::write(Path::new(archive), &extracted, store, sources.map(Path::new))
+        .map_err(|e| e.to_string())?;
+    println!(
+        "Extracted {} runs of {} files into {}{}",
+        extracted.measurements.len(),
+        extracted.files.len(),
+        archive,
+        sources.map_or(String::new(), |_| format!(", {} workbooks added", added))
+    );
+    Ok(())
+}
@@ -0,0 +1,223 @@
+use super::settings::Settings;
+use super::Run;
+use crate::calamine_helper::MyRange;
+use crate::ingest::{ErrorKind, IngestError};
+
+//Clarius (4200A-SCS) workbooks have no "=====" banner above a run, every "Test Name" row
+//starts a run and the run names its data sheet in one of its values, e.g. the test name
+const NOT_DATA_SHEETS: [&str; 2] = ["Settings", "Calc"];
+const TEST_NAME: &str = "Test Name";
+const TIME_STAMP: &str = "Last Executed";
+
+pub fn is_clarius(sett: &MyRange) -> bool {
+    !sett.it.rows().any(|row| {
+        row.first()
+            .and_then(|cell| cell.get_string())
+            .is_some_and(|label| label.starts_with("==="))
+    })
+}
+
+pub fn runs(sett: &MyRange, sheet_names: &[String]) -> Result<Vec<Run>, IngestError> {
+    let data_sheets: Vec<&String> = sheet_names
+        .iter()
+        .filter(|name| !NOT_DATA_SHEETS.contains(&name.as_str()))
+        .collect();
+    let starts: Vec<usize> = sett
+        .it
+        .rows()
+        .enumerate()
+        .filter_map(|(row, cells)| {
+            cells
+                .first()
+                .and_then(|cell| cell.get_string())
+                .filter(|label| label.trim() == TEST_NAME)
+                .map(|_| row)
+        })
+        .collect();
+    if starts.is_empty() {
+        return Err(
+            IngestError::new(ErrorKind::MissingLabel(TEST_NAME.to_string())).in_sheet("Settings"),
+        );
+    }
+
+    let ends = starts
+        .iter()
+        .skip(1)
+        .map(|next| next - 1)
+        .chain(std::iter::once(sett.end().0));
+    let mut runs: Vec<Run> = vec![];
+    for (start, end) in starts.iter().zip(ends) {
+        let mut settings = Settings::parse(&sett.sub_range((*start, 0), (end, sett.end().1)));
+        let at = |error: IngestError| sett.error_at((*start, 0), error);
+        let named: Vec<&String> = data_sheets
+            .iter()
+            .copied()
+            .filter(|sheet| settings.global.values().any(|value| value == *sheet))
+            .collect();
+        //a single run and a single data sheet belong together without being named
+        let data_sheet = match named.as_slice() {
+            [sheet] => *sheet,
+            [] if starts.len() == 1 && data_sheets.len() == 1 => data_sheets[0],
+            [] => {
+                return Err(at(IngestError::unexpected(
+                    "the name of a data sheet",
+                    settings
+                        .global
+                        .get(TEST_NAME)
+                        .map_or("", |name| name.as_str()),
+                )))
+            }
+            _ => {
+                let names: Vec<&str> = named.iter().map(|name| name.as_str()).collect();
+                return Err(at(IngestError::unexpected(
+                    "the name of one data sheet",
+                    names.join(", ").as_str(),
+                )));
+            }
+        };
+        if runs.iter().any(|run| run.data_sheet == *data_sheet) {
+            return Err(at(IngestError::unexpected(
+                "a data sheet of its own",
+                data_sheet,
+            )));
+        }
+        if let Some(serial) = settings
+            .global
+            .get(TIME_STAMP)
+            .and_then(|value| value.parse::<f64>().ok())
+        {
+            settings
+                .global
+                .insert(TIME_STAMP.to_string(), from_excel_serial(serial));
+        }
+        runs.push(Run {
+            data_sheet: data_sheet.clone(),
+            device_name: data_sheet.clone(),
+            settings,
+        });
+    }
+    Ok(runs)
+}
+
+//xlsx stores dates as days since 1899-12-30, written back in the KITE "MM/DD/YYYY hh:mm:ss" form
+fn from_excel_serial(serial: f64) -> String {
+    let seconds = (serial * 86400.0).round() as i64;
+    let (days, seconds) = (seconds.div_euclid(86400), seconds.rem_euclid(86400));
+
+    //civil date from days since 1970-01-01, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days - 25569 + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{:0>2}/{:0>2}/{} {:0>2}:{:0>2}:{:0>2}",
+        month,
+        day,
+        year,
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::measurement::metadata::MetadataParser;
+    use crate::measurement::timestamp::TimeStamp;
+    use crate::measurement::Measurement;
+    use calamine::{DataType, Range};
+
+    const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/clarius");
+    const WORKBOOK: &str = "Process=GF22 Die=B2 T=77K/W=1um L=0.5um vtlin.xlsx";
+
+    fn settings_sheet(rows: &[&[&str]]) -> MyRange {
+        let mut range = Range::new((0, 0), (rows.len() as u32 - 1, 1));
+        for (row, cells) in rows.iter().enumerate() {
+            for (column, cell) in cells.iter().enumerate() {
+                range.set_value(
+                    (row as u32, column as u32),
+                    DataType::String(cell.to_string()),
+                );
+            }
+        }
+        MyRange::new(range, "Settings")
+    }
+
+    fn sheets(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    //the data sheets of the fixture are in the opposite order of their settings blocks
+    #[test]
+    fn runs_are_matched_with_their_sheets_by_name() {
+        let config = Config::default();
+        let metadata = MetadataParser::new(&config.metadata, &config.process_registry()).unwrap();
+        let measurements = Measurement::extract(FIXTURES, WORKBOOK, &metadata).unwrap();
+        let runs: Vec<(&str, &str, usize, String, Option<f64>)> = measurements
+            .iter()
+            .map(|m| {
+                (
+                    m.sheet_name.as_str(),
+                    m.settings.global["Test Name"].as_str(),
+                    m.test_data[0].data[0].len(),
+                    m.test_parameter.measurement_speed.to_string(),
+                    m.terminals[1].operational_mode.stop,
+                )
+            })
+            .collect();
+        assert_eq!(
+            runs,
+            vec![
+                ("vtlin#1@1", "vtlin#1@1", 5, "Normal".to_string(), Some(1.5)),
+                ("vtlin#1@2", "vtlin#1@2", 3, "Fast".to_string(), Some(3.0)),
+            ]
+        );
+        assert_eq!(
+            measurements[1].test_time_stamp,
+            TimeStamp::parse("11/22/2019 13:30:00").unwrap()
+        );
+        assert_eq!(measurements[0].device.width, Some(1000.0));
+        assert_eq!(measurements[0].device.length, Some(500.0));
+    }
+
+    #[test]
+    fn a_run_without_its_sheet_is_reported() {
+        let sett = settings_sheet(&[
+            &["Test Name", "Run1"],
+            &["Mode", "Sweeping"],
+            &["Test Name", "vtlin#1@2"],
+            &["Mode", "Sweeping"],
+        ]);
+        let error = runs(&sett, &sheets(&["Run1", "Run2", "Calc", "Settings"])).unwrap_err();
+        assert_eq!(error.cell, Some((2, 0)));
+        assert_eq!(
+            error.kind,
+            ErrorKind::Unexpected {
+                expected: "the name of a data sheet".to_string(),
+                found: "vtlin#1@2".to_string()
+            }
+        );
+        //two runs naming the same sheet
+        let sett = settings_sheet(&[&["Test Name", "Run1"], &["Test Name", "Run1"]]);
+        let error = runs(&sett, &sheets(&["Run1", "Run2", "Settings"])).unwrap_err();
+        assert_eq!(error.cell, Some((1, 0)));
+        //a single run needs no name
+        let sett = settings_sheet(&[&["Test Name", "vtlin#1@1"]]);
+        let runs = runs(&sett, &sheets(&["Run1", "Settings"])).unwrap();
+        assert_eq!(runs[0].data_sheet, "Run1");
+    }
+
+    #[test]
+    fn excel_serial_dates() {
+        assert_eq!(from_excel_serial(43790.5625), "11/21/2019 13:30:00");
+        assert_eq!(from_excel_serial(1.0), "12/31/1899 00:00:00");
+    }
+}
@@ -0,0 +1,202 @@
+use super::process::ProcessRegistry;
+use crate::ingest::{ErrorKind, IngestError};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+//how the device fields are read from "relative/path/of/file/sheet name"
+//templates are tried first and the patterns are the fallback, the first source that resolves a field wins
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MetadataSchema {
+    //e.g. "Process={process} Die={die} T={temperature}/{test}#{index}", a {field} never spans a '/'
+    #[serde(default)]
+    pub templates: Vec<String>,
+    //regexes with named capture groups, e.g. "(?i)fingers=(?P<fingers>\\d+)"
+    #[serde(default = "default_patterns")]
+    pub patterns: Vec<String>,
+    //fields without which a file is reported as failed
+    #[serde(default = "default_required")]
+    pub required: Vec<String>,
+    //report files for which any field of the schema is missing
+    #[serde(default)]
+    pub strict: bool,
+}
+
+impl Default for MetadataSchema {
+    fn default() -> Self {
+        MetadataSchema {
+            templates: vec![],
+            patterns: default_patterns(),
+            required: default_required(),
+            strict: false,
+        }
+    }
+}
+
+//the "Process=MINOXG Die=A1 T=293k/W=0.25um L=0.35um" layout of the KITE libraries,
+//the metadata section of config.json holds a copy
+const DEFAULT_PATTERNS: [&str; 6] = [
+    r"(?i)(?:^|[/\s])process=(?P<process>[^/\s]+)",
+    r"(?i)(?:^|[/\s])die=(?P<die>[^/\s]+)",
+    r"(?i)(?:^|[/\s])t=(?P<temperature>[0-9.]+)\s*k?(?:$|[/\s])",
+    r"(?i)(?:^|[/\s])w=(?P<width>[0-9.]+\s*[a-zµ]*m)(?:$|[/\s])",
+    r"(?i)(?:^|[/\s])l=(?P<length>[0-9.]+\s*[a-zµ]*m)(?:$|[/\s])",
+    r"(?i)(?:^|[/\s])fingers=(?P<fingers>[0-9]+)",
+];
+
+const DEFAULT_REQUIRED: [&str; 2] = ["process", "die"];
+
+fn default_patterns() -> Vec<String> {
+    DEFAULT_PATTERNS
+        .iter()
+        .map(|pattern| pattern.to_string())
+        .collect()
+}
+
+fn default_required() -> Vec<String> {
+    DEFAULT_REQUIRED
+        .iter()
+        .map(|field| field.to_string())
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+pub struct MetadataParser {
+    sources: Vec<Regex>,
+    required: Vec<String>,
+    strict: bool,
+    processes: ProcessRegistry,
+}
+
+//"{name}" becomes a named group, everything else is matched literally
+fn template_to_regex(template: &str) -> String {
+    let mut regex = String::from("(?:^|/)");
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let end = match rest[start..].find('}') {
+            Some(end) => start + end,
+            None => break,
+        };
+        regex.push_str(&regex::escape(&rest[..start]));
+        regex.push_str(&format!("(?P<{}>[^/]+?)", &rest[start + 1..end]));
+        rest = &rest[end + 1..];
+    }
+    regex.push_str(&regex::escape(rest));
+    if !template.ends_with('/') {
+        regex.push_str("(?:$|/)");
+    }
+    regex
+}
+
+impl MetadataParser {
+    pub fn new(
+        schema: &MetadataSchema,
+        processes: &ProcessRegistry,
+    ) -> Result<MetadataParser, regex::Error> {
+        let sources = schema
+            .templates
+            .iter()
+            .map(|template| template_to_regex(template))
+            .chain(schema.patterns.iter().cloned())
+            .map(|source| Regex::new(source.as_str()))
+            .collect::<Result<Vec<Regex>, regex::Error>>()?;
+        Ok(MetadataParser {
+            sources,
+            required: schema.required.clone(),
+            strict: schema.strict,
+            processes: processes.clone(),
+        })
+    }
+
+    //the captured process is looked up here to get its registry name
+    pub fn processes(&self) -> &ProcessRegistry {
+        &self.processes
+    }
+
+    //field name -> value for the file and sheet, windows and unix separators are treated alike
+    pub fn parse(
+        &self,
+        path: &str,
+        sheet_name: &str,
+    ) -> Result<BTreeMap<String, String>, IngestError> {
+        let subject = format!(
+            "{}/{}",
+            path.replace('\\', "/").trim_start_matches('/'),
+            sheet_name
+        );
+        let mut fields: BTreeMap<String, String> = BTreeMap::new();
+        for source in self.sources.iter() {
+            if let Some(captures) = source.captures(subject.as_str()) {
+                for name in source.capture_names().flatten() {
+                    if let Some(value) = captures.name(name) {
+                        fields
+                            .entry(name.to_string())
+                            .or_insert_with(|| value.as_str().trim().to_string());
+                    }
+                }
+            }
+        }
+
+        let missing = if self.strict {
+            self.sources
+                .iter()
+                .flat_map(|source| source.capture_names().flatten())
+                .find(|name| !fields.contains_key(*name))
+                .map(|name| name.to_string())
+        } else {
+            None
+        };
+        match self
+            .required
+            .iter()
+            .find(|name| !fields.contains_key(*name))
+            .cloned()
+            .or(missing)
+        {
+            Some(name) => Err(IngestError::new(ErrorKind::MissingMetadata(name))),
+            None => Ok(fields),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //a config without a metadata schema reads the KITE layout
+    #[test]
+    fn defaults_read_the_kite_layout() {
+        let schema: MetadataSchema = serde_json::from_str("{}").unwrap();
+        assert_eq!(schema.required, vec!["process", "die"]);
+        let parser = MetadataParser::new(&schema, &ProcessRegistry::new(&[])).unwrap();
+        let fields = parser
+            .parse(
+                "Process=MINOXG Die=A1 T=293k/W=0.25um L=0.35um fingers=4.xls",
+                "Run1",
+            )
+            .unwrap();
+        let expected: BTreeMap<String, String> = [
+            ("die", "A1"),
+            ("fingers", "4"),
+            ("length", "0.35um"),
+            ("process", "MINOXG"),
+            ("temperature", "293"),
+            ("width", "0.25um"),
+        ]
+        .iter()
+        .map(|(field, value)| (field.to_string(), value.to_string()))
+        .collect();
+        assert_eq!(fields, expected);
+    }
+
+    //the example config lists the defaults
+    #[test]
+    fn config_json_holds_the_defaults() {
+        let config =
+            crate::config::Config::load(concat!(env!("CARGO_MANIFEST_DIR"), "/config.json"))
+                .unwrap();
+        let defaults = MetadataSchema::default();
+        assert_eq!(config.metadata.patterns, defaults.patterns);
+        assert_eq!(config.metadata.required, defaults.required);
+    }
+}
@@ -1,10 +1,22 @@
 use crate::calamine_helper::MyRange;
+use crate::ingest::{ErrorKind, IngestError};
 use crate::Extract;
 use calamine::*;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+pub mod clarius;
 
 pub mod device;
 
+pub mod metadata;
+
+pub mod process;
+
+pub mod series;
+
+pub mod settings;
+
 pub mod testparameter;
 
 pub mod timestamp;
@@ -17,7 +29,10 @@ pub mod terminal_parameter;
 pub struct Measurement {
     //file properties
     pub id: String,
-    file_path: String,
+    //"YYYYMMDD-n" numbered in the order of scanning, only for display
+    #[serde(default)]
+    pub alias: String,
+    pub file_path: String,
     pub sheet_name: String,
     //Device Under Test
     pub device: device::Device,
@@ -25,15 +40,32 @@ pub struct Measurement {
     pub test_parameter: testparameter::TestParameter,
     pub test_time_stamp: timestamp::TimeStamp,
     pub terminals: Vec<terminal_parameter::TerminalParameter>,
+    //every row of the run in the "Settings" sheet
+    #[serde(default)]
+    pub settings: settings::Settings,
     //data
+    #[serde(default)]
+    pub series: Vec<series::Series>,
     pub test_data: Vec<testdata::TestData>,
 }
 
+//one run of a workbook before it is turned into a measurement
+#[derive(Debug)]
+pub struct Run {
+    pub data_sheet: String,
+    //sheet name or label the device is described by, e.g. "W=0.25um L=0.35um"
+    pub device_name: String,
+    pub settings: settings::Settings,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct MeasurementCompact {
     //file properties
     pub id: String,
-    file_path: String,
+    //"YYYYMMDD-n" numbered in the order of scanning, only for display
+    #[serde(default)]
+    pub alias: String,
+    pub file_path: String,
     pub sheet_name: String,
     //Device Under Test
     pub device: device::Device,
@@ -41,114 +73,208 @@ pub struct MeasurementCompact {
     pub test_parameter: testparameter::TestParameter,
     pub test_time_stamp: timestamp::TimeStamp,
     pub terminals: Vec<terminal_parameter::TerminalParameter>,
+    //every row of the run in the "Settings" sheet
+    #[serde(default)]
+    pub settings: settings::Settings,
     //data
+    #[serde(default)]
+    pub series: Vec<series::Series>,
     pub test_data: Vec<testdata::TestDataCompact>,
 }
 
-impl Measurement {
-    pub fn to_compact(&self) -> MeasurementCompact {
-        MeasurementCompact {
-            id: self.id.clone(),
-            file_path: self.file_path.clone(),
-            sheet_name: self.sheet_name.clone(),
-            device: self.device.clone(),
-            test_parameter: self.test_parameter.clone(),
+//date of the run and a hash of file, sheet and run time stamp, so rescanning a library or
+//merging two of them gives every run the same id again
+//the hash takes the path of the file in the library and not its content, the runs of an edited
+//file keep their id, those of a moved or renamed file get new ids
+pub fn stable_id(file_path: &str, sheet_name: &str, time: timestamp::TimeStamp) -> String {
+    let identity = format!(
+        "{}\n{}\n{:0>4}-{:0>2}-{:0>2} {:0>2}:{:0>2}:{:0>2}",
+        crate::ingest::path::normalize(file_path),
+        sheet_name,
+        time.year,
+        time.month,
+        time.day,
+        time.hour,
+        time.minute,
+        time.second
+    );
+    let hash = format!("{:x}", Sha256::digest(identity.as_bytes()));
+    format!(
+        "{:0>4}{:0>2}{:0>2}-{}",
+        time.year,
+        time.month,
+        time.day,
+        &hash[..8]
+    )
+}
+
+impl MeasurementCompact {
+    //the full measurement once the samples are loaded from the store
+    pub fn with_data(self, test_data: Vec<testdata::TestData>) -> Measurement {
+        Measurement {
+            id: self.id,
+            alias: self.alias,
+            file_path: self.file_path,
+            sheet_name: self.sheet_name,
+            device: self.device,
+            test_parameter: self.test_parameter,
             test_time_stamp: self.test_time_stamp,
-            terminals: self.terminals.clone(),
-            test_data: self
-                .test_data
-                .clone()
-                .into_iter()
-                .map(|t| t.to_compact())
-                .collect::<Vec<testdata::TestDataCompact>>(),
+            terminals: self.terminals,
+            settings: self.settings,
+            series: self.series,
+            test_data,
         }
     }
 
+    pub fn stable_id(&self) -> String {
+        stable_id(&self.file_path, &self.sheet_name, self.test_time_stamp)
+    }
+}
+
+impl Measurement {
+    //the index entry and the samples, which are stored apart from each other
+    pub fn split(self) -> (MeasurementCompact, Vec<testdata::TestData>) {
+        let compact = MeasurementCompact {
+            id: self.id,
+            alias: self.alias,
+            file_path: self.file_path,
+            sheet_name: self.sheet_name,
+            device: self.device,
+            test_parameter: self.test_parameter,
+            test_time_stamp: self.test_time_stamp,
+            terminals: self.terminals,
+            settings: self.settings,
+            series: self.series,
+            test_data: self.test_data.iter().map(|t| t.to_compact()).collect(),
+        };
+        (compact, self.test_data)
+    }
+
     fn extract_origin_positions(sheet: &crate::calamine_helper::MyRange) -> Vec<(usize, usize)> {
         sheet
             .it
             .cells()
             .filter_map(|cell| {
-                (if "Test Name" == cell.2.get_string().unwrap_or("") {
+                if "Test Name" == cell.2.get_string().unwrap_or("") && cell.0 >= 3 {
                     Some((cell.0 - 3, cell.1))
                 } else {
                     None
-                })
+                }
             })
             .collect()
     }
 
-    fn extract_sheet_name(sheet: &MyRange) -> Option<&str> {
-        sheet.it.get((0, 0))?.get_string()
+    fn extract_sheet_name(sheet: &MyRange) -> Result<&str, IngestError> {
+        sheet.get_string((0, 0))
     }
 
-    pub fn extract(root: String, relative_path: String, storage: &mut crate::database::Database) {
-        let path = format!("{}{}", root.as_str(), relative_path.as_str());
-        if !storage.files_scanned_before.contains(&relative_path) {
-            storage.files_scanned_before.push(relative_path.clone());
-            let mut workbook: Xls<_> = open_workbook(&path).expect("cannot open file");
-            let sett = MyRange::new(
-                workbook
-                    .worksheet_range("Settings")
-                    .expect("Cannot find 'Settings'")
-                    .unwrap(),
-            );
-            let sheet = sett.sub_range((1, 0), sett.end());
-            let positions_of_test_name: Vec<(usize, usize)> =
-                Measurement::extract_origin_positions(&sheet);
-            let mut subranges: Vec<MyRange> = positions_of_test_name
-                .iter()
-                .zip(positions_of_test_name.iter().skip(1))
-                .map(|((row, _), (row_next, _))| {
-                    sheet.sub_range((*row, 0), (row_next - 1, sheet.end().1))
-                })
-                .collect();
-            subranges.push(sheet.sub_range(*positions_of_test_name.last().unwrap(), sheet.end()));
-            for run_setting in subranges.iter() {
-                let sheet_name: String = Measurement::extract_sheet_name(run_setting)
-                    .expect("Sheet name extraction failure")
-                    .to_string();
-                let data_sheet = MyRange::new(
-                    workbook
-                        .worksheet_range(sheet_name.as_str())
-                        .expect(
-                            format!("Can not find sheet with the following name: {}", sheet_name)
-                                .as_str(),
-                        )
-                        .unwrap(),
-                );
-                let test_time_stamp =
-                    timestamp::TimeStamp::extract(&run_setting).expect("Error parsing timestamp");
-                let id = storage.generate_id(test_time_stamp);
-
-                let test_parameter: testparameter::TestParameter =
-                    testparameter::TestParameter::extract(&run_setting)
-                        .expect("Test type extraction failure");
-                let device: device::Device =
-                    device::Device::extract(relative_path.clone(), sheet_name.clone());
-                let terminals = (1..(run_setting.end().1 + 1))
-                    .into_iter()
-                    .map(|i| run_setting.sub_range((14, i), (run_setting.end().0, i)))
-                    .map(|c| {
-                        terminal_parameter::TerminalParameter::extract(
-                            &c,
-                            &test_parameter.test_type,
-                        )
-                        .expect("Terminal Parameter extraction failure")
-                    })
-                    .collect();
-                let test_data = testdata::TestData::extract(&data_sheet);
-                storage.measurements.push(Measurement {
-                    id,
-                    file_path: relative_path.clone(),
-                    sheet_name,
-                    device,
-                    test_parameter,
-                    test_time_stamp,
-                    terminals,
-                    test_data,
+    pub fn open_sheet<R: Reader>(workbook: &mut R, name: &str) -> Result<MyRange, IngestError>
+    where
+        R::Error: std::fmt::Display,
+    {
+        let range = workbook
+            .worksheet_range(name)
+            .ok_or_else(|| IngestError::new(ErrorKind::MissingSheet(name.to_string())))?
+            .map_err(|e| IngestError::new(ErrorKind::Open(e.to_string())).in_sheet(name))?;
+        if range.is_empty() {
+            return Err(IngestError::new(ErrorKind::EmptySheet).in_sheet(name));
+        }
+        Ok(MyRange::new(range, name))
+    }
+
+    //every run of the workbook, or an error if one of them fails
+    pub fn extract(
+        root: &str,
+        relative_path: &str,
+        metadata: &metadata::MetadataParser,
+    ) -> Result<Vec<Measurement>, IngestError> {
+        let path = crate::ingest::path::absolute(root, relative_path);
+        let mut workbook = open_workbook_auto(&path)
+            .map_err(|e| IngestError::new(ErrorKind::Open(e.to_string())).in_file(relative_path))?;
+        Measurement::extract_runs(&mut workbook, relative_path, metadata)
+            .map_err(|e| e.in_file(relative_path))
+    }
+
+    //KITE writes a banner with the data sheet name above every "Test Name" row
+    fn kite_runs(sett: &MyRange) -> Result<Vec<Run>, IngestError> {
+        let sheet = sett.sub_range((1, 0), sett.end());
+        let positions_of_test_name: Vec<(usize, usize)> =
+            Measurement::extract_origin_positions(&sheet);
+        let last_position = *positions_of_test_name.last().ok_or_else(|| {
+            IngestError::new(ErrorKind::MissingLabel("Test Name".to_string())).in_sheet("Settings")
+        })?;
+        let mut subranges: Vec<MyRange> = positions_of_test_name
+            .iter()
+            .zip(positions_of_test_name.iter().skip(1))
+            .map(|((row, _), (row_next, _))| {
+                sheet.sub_range((*row, 0), (row_next - 1, sheet.end().1))
+            })
+            .collect();
+        subranges.push(sheet.sub_range(last_position, sheet.end()));
+
+        subranges
+            .iter()
+            .map(|run_setting| {
+                let sheet_name: String = Measurement::extract_sheet_name(run_setting)?.to_string();
+                Ok(Run {
+                    data_sheet: sheet_name.clone(),
+                    device_name: sheet_name,
+                    settings: settings::Settings::parse(run_setting),
                 })
-            }
+            })
+            .collect()
+    }
+
+    fn extract_runs<R: Reader>(
+        workbook: &mut R,
+        relative_path: &str,
+        metadata: &metadata::MetadataParser,
+    ) -> Result<Vec<Measurement>, IngestError>
+    where
+        R::Error: std::fmt::Display,
+    {
+        let sett = Measurement::open_sheet(workbook, "Settings")?;
+        let runs = if clarius::is_clarius(&sett) {
+            clarius::runs(&sett, workbook.sheet_names())?
+        } else {
+            Measurement::kite_runs(&sett)?
+        };
+
+        let mut measurements = vec![];
+        for run in runs {
+            let Run {
+                data_sheet,
+                device_name,
+                settings,
+            } = run;
+            let data_sheet = Measurement::open_sheet(workbook, data_sheet.as_str())?;
+            let test_time_stamp = timestamp::TimeStamp::extract(&settings.global())?;
+
+            let test_parameter: testparameter::TestParameter =
+                testparameter::TestParameter::extract(&settings.global())?;
+            let device: device::Device =
+                device::Device::extract(metadata, relative_path, device_name.as_str())
+                    .map_err(|e| e.in_sheet(data_sheet.sheet_name()))?;
+            let terminals = settings
+                .terminals()
+                .map(|column| terminal_parameter::TerminalParameter::extract(&column))
+                .collect::<Result<Vec<terminal_parameter::TerminalParameter>, IngestError>>()?;
+            let test_data = testdata::TestData::extract(&data_sheet)?;
+            let series = series::Series::from_run(&terminals, &test_data);
+            measurements.push(Measurement {
+                id: String::new(),
+                alias: String::new(),
+                file_path: relative_path.to_string(),
+                sheet_name: data_sheet.sheet_name().to_string(),
+                device,
+                test_parameter,
+                test_time_stamp,
+                terminals,
+                settings,
+                series,
+                test_data,
+            })
         }
+        Ok(measurements)
     }
 }
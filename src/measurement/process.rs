@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+
+//name of the process of a wafer, the registry name when known, otherwise as written in the path
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[serde(transparent)]
+pub struct Process(pub String);
+
+impl std::fmt::Display for Process {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+//technology data of a process as given in the config
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProcessDefinition {
+    pub name: String,
+    //other spellings found in paths, e.g. "22FDX" for "GF22"
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    //oxide thickness in nm
+    #[serde(default)]
+    pub tox: Option<f64>,
+    //oxide capacitance per area in F/m²
+    #[serde(default)]
+    pub cox: Option<f64>,
+    //nominal supply voltage in V
+    #[serde(default)]
+    pub vdd: Option<f64>,
+    //relative permittivity of the gate dielectric
+    #[serde(default)]
+    pub dielectric_constant: Option<f64>,
+    //gate stack and other remarks
+    #[serde(default)]
+    pub notes: String,
+}
+
+impl ProcessDefinition {
+    pub fn new(name: &str) -> ProcessDefinition {
+        ProcessDefinition {
+            name: name.to_string(),
+            aliases: vec![],
+            tox: None,
+            cox: None,
+            vdd: None,
+            dielectric_constant: None,
+            notes: String::new(),
+        }
+    }
+
+    fn is_named(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+            || self
+                .aliases
+                .iter()
+                .any(|alias| alias.eq_ignore_ascii_case(name))
+    }
+}
+
+//the processes known to this library, loaded from the config at startup
+#[derive(Debug, Clone, Default)]
+pub struct ProcessRegistry {
+    definitions: Vec<ProcessDefinition>,
+}
+
+impl ProcessRegistry {
+    pub fn new(definitions: &[ProcessDefinition]) -> ProcessRegistry {
+        ProcessRegistry {
+            definitions: definitions.to_vec(),
+        }
+    }
+
+    //by name or alias, ignoring case
+    pub fn definition(&self, name: &str) -> Option<&ProcessDefinition> {
+        self.definitions
+            .iter()
+            .find(|definition| definition.is_named(name.trim()))
+    }
+
+    //unknown processes are kept as written instead of being dropped
+    pub fn resolve(&self, name: &str) -> Process {
+        match self.definition(name) {
+            Some(definition) => Process(definition.name.clone()),
+            None => Process(name.trim().to_string()),
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use super::terminal_parameter::opmode::OpModeType;
+use super::terminal_parameter::TerminalParameter;
+use super::testdata::{terminal::Terminal, units::Unit, TestData};
+use serde::{Deserialize, Serialize};
+
+//one curve of a run, either a step of a stepped terminal or one of the appended runs
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Series {
+    pub number: usize,
+    pub label: String,
+    pub bias: Option<SteppedBias>,
+}
+
+//value of the stepped terminal while this series was measured
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct SteppedBias {
+    pub terminal: Terminal,
+    pub unit: Unit,
+    pub value: f64,
+}
+
+impl Series {
+    pub fn from_run(terminals: &[TerminalParameter], test_data: &[TestData]) -> Vec<Series> {
+        let mut numbers: Vec<usize> = test_data.iter().flat_map(|t| t.series_numbers()).collect();
+        numbers.sort_unstable();
+        numbers.dedup();
+
+        //the step n of a stepped terminal is at start + (n - 1) * stepsize
+        let stepped = terminals.iter().find_map(|parameter| {
+            let mode = parameter.operational_mode;
+            let unit = match mode.op_type {
+                OpModeType::VoltageStep => Unit::Voltage,
+                OpModeType::CurrentStep => Unit::Current,
+                _ => return None,
+            };
+            Some((
+                Terminal::from(parameter.terminal),
+                unit,
+                mode.start?,
+                mode.stepsize?,
+            ))
+        });
+
+        numbers
+            .into_iter()
+            .map(|number| match stepped {
+                Some((terminal, unit, start, stepsize)) => {
+                    //rounded to hide the float error of the multiplication
+                    let value = ((start + number.saturating_sub(1) as f64 * stepsize) * 1e12)
+                        .round()
+                        / 1e12;
+                    Series {
+                        number,
+                        label: format!(
+                            "{}{}={}",
+                            unit.to_string_concise(),
+                            terminal.to_string_concise(),
+                            value
+                        ),
+                        bias: Some(SteppedBias {
+                            terminal,
+                            unit,
+                            value,
+                        }),
+                    }
+                }
+                None => Series {
+                    number,
+                    label: format!("Run {}", number),
+                    bias: None,
+                },
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,239 @@
+use crate::calamine_helper::MyRange;
+use crate::ingest::{ErrorKind, IngestError};
+use calamine::DataType;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+const TERMINAL_BLOCK: &str = "Device Terminal";
+const FORMULAS: &str = "Formulas";
+
+//label -> value maps of one run in the "Settings" sheet, kept verbatim
+//rows above the "Device Terminal" row are global, the rows below it hold a value per terminal column
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Settings {
+    pub global: BTreeMap<String, String>,
+    pub terminals: Vec<BTreeMap<String, String>>,
+    #[serde(default)]
+    pub formulas: Vec<String>,
+    #[serde(skip)]
+    sheet: String,
+    #[serde(skip)]
+    global_cells: HashMap<String, (u32, u32)>,
+    #[serde(skip)]
+    terminal_cells: Vec<HashMap<String, (u32, u32)>>,
+}
+
+//view on either the global rows or a single terminal column
+pub struct Section<'a> {
+    values: &'a BTreeMap<String, String>,
+    cells: &'a HashMap<String, (u32, u32)>,
+    sheet: &'a str,
+}
+
+fn cell_text(cell: Option<&DataType>) -> Option<String> {
+    match cell? {
+        DataType::Empty => None,
+        DataType::String(string) if string.trim().is_empty() => None,
+        DataType::String(string) => Some(string.trim().to_string()),
+        other => Some(other.to_string()),
+    }
+}
+
+impl Settings {
+    pub fn parse(run: &MyRange) -> Settings {
+        let mut settings = Settings {
+            sheet: run.sheet_name().to_string(),
+            ..Settings::default()
+        };
+        let (height, width) = run.it.get_size();
+        let mut in_terminal_block = false;
+        let mut in_formulas = false;
+
+        for row in 0..height {
+            let label = match cell_text(run.it.get((row, 0))) {
+                Some(label) => label,
+                None => {
+                    in_terminal_block = false;
+                    in_formulas = false;
+                    continue;
+                }
+            };
+            let values: Vec<(usize, String)> = (1..width)
+                .filter_map(|column| cell_text(run.it.get((row, column))).map(|v| (column, v)))
+                .collect();
+
+            //formulas are written as "NAME = EXPRESSION" in the label column only
+            if label == FORMULAS {
+                in_formulas = true;
+                continue;
+            }
+            if in_formulas {
+                settings.formulas.push(label);
+                continue;
+            }
+
+            if label == TERMINAL_BLOCK {
+                in_terminal_block = true;
+                settings.terminals = values.iter().map(|_| BTreeMap::new()).collect();
+                settings.terminal_cells = values.iter().map(|_| HashMap::new()).collect();
+            }
+
+            //a row with only a value in the first column closes the terminal block
+            let per_terminal =
+                settings.terminals.len() == 1 || values.iter().any(|(column, _)| *column > 1);
+            if in_terminal_block && per_terminal {
+                for (column, value) in values {
+                    if let Some(terminal) = settings.terminals.get_mut(column - 1) {
+                        let key = Settings::add(terminal, &label, value);
+                        settings.terminal_cells[column - 1]
+                            .insert(key, run.absolute((row, column)));
+                    }
+                }
+            } else {
+                in_terminal_block = false;
+                if let Some((column, value)) = values.into_iter().next() {
+                    let key = Settings::add(&mut settings.global, &label, value);
+                    settings
+                        .global_cells
+                        .insert(key, run.absolute((row, column)));
+                }
+            }
+        }
+        settings
+    }
+
+    //a label that is repeated within a section is kept as "label (2)", "label (3)", ..., the first
+    //row keeps the label, returns the label the value was kept under
+    pub fn add(values: &mut BTreeMap<String, String>, label: &str, value: String) -> String {
+        let mut key = label.to_string();
+        let mut n = 1;
+        while values.contains_key(&key) {
+            n += 1;
+            key = format!("{} ({})", label, n);
+        }
+        values.insert(key.clone(), value);
+        key
+    }
+
+    //value of a global row, or of a terminal row when the terminal is given by its name (e.g. "Drain")
+    pub fn value(&self, terminal: Option<&str>, label: &str) -> Option<&str> {
+        let values = match terminal {
+            None => &self.global,
+            Some(name) => self.terminals.iter().find(|values| {
+                Settings::terminal_name(values)
+                    .is_some_and(|terminal| terminal.eq_ignore_ascii_case(name))
+            })?,
+        };
+        values.get(label).map(|value| value.as_str())
+    }
+
+    //the name of a terminal column as given in its "Device Terminal" row
+    pub fn terminal_name(values: &BTreeMap<String, String>) -> Option<&str> {
+        values.get(TERMINAL_BLOCK).map(|terminal| terminal.as_str())
+    }
+
+    pub fn global(&self) -> Section<'_> {
+        Section {
+            values: &self.global,
+            cells: &self.global_cells,
+            sheet: self.sheet.as_str(),
+        }
+    }
+
+    pub fn terminals(&self) -> impl Iterator<Item = Section<'_>> {
+        let sheet = self.sheet.as_str();
+        self.terminals
+            .iter()
+            .zip(self.terminal_cells.iter())
+            .map(move |(values, cells)| Section {
+                values,
+                cells,
+                sheet,
+            })
+    }
+}
+
+impl<'a> Section<'a> {
+    //first label that is present, labels differ between KITE versions and test types
+    fn find(&self, labels: &[&str]) -> Option<(&'a str, &'a str)> {
+        labels.iter().find_map(|label| {
+            self.values
+                .get_key_value(*label)
+                .map(|(key, value)| (key.as_str(), value.as_str()))
+        })
+    }
+
+    pub fn error(&self, label: &str, error: IngestError) -> IngestError {
+        match self.cells.get(label) {
+            Some(cell) => error.in_sheet(self.sheet).at(*cell),
+            None => error.in_sheet(self.sheet),
+        }
+    }
+
+    pub fn get(&self, labels: &[&str]) -> Result<&'a str, IngestError> {
+        self.find(labels).map(|(_, value)| value).ok_or_else(|| {
+            IngestError::new(ErrorKind::MissingLabel(labels[0].to_string())).in_sheet(self.sheet)
+        })
+    }
+
+    pub fn get_f64(&self, labels: &[&str]) -> Result<f64, IngestError> {
+        let (label, value) = self.find(labels).ok_or_else(|| {
+            IngestError::new(ErrorKind::MissingLabel(labels[0].to_string())).in_sheet(self.sheet)
+        })?;
+        value
+            .parse::<f64>()
+            .map_err(|_| self.error(label, IngestError::unexpected("a number", value)))
+    }
+
+    //unexpected value for the first present label
+    pub fn unexpected(&self, labels: &[&str], expected: &str) -> IngestError {
+        match self.find(labels) {
+            Some((label, value)) => self.error(label, IngestError::unexpected(expected, value)),
+            None => IngestError::new(ErrorKind::MissingLabel(labels[0].to_string()))
+                .in_sheet(self.sheet),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use calamine::Range;
+
+    fn sheet(rows: &[&[&str]]) -> MyRange {
+        let mut range = Range::new((0, 0), (rows.len() as u32 - 1, 2));
+        for (row, cells) in rows.iter().enumerate() {
+            for (column, cell) in cells.iter().enumerate() {
+                range.set_value(
+                    (row as u32, column as u32),
+                    DataType::String(cell.to_string()),
+                );
+            }
+        }
+        MyRange::new(range, "Settings")
+    }
+
+    #[test]
+    fn repeated_labels_are_kept() {
+        let settings = Settings::parse(&sheet(&[
+            &["Comment", "first"],
+            &["Comment", "second"],
+            &["Device Terminal", "Drain", "Gate"],
+            &["Compliance", "0.1", "0.01"],
+            &["Compliance", "0.2", "0.02"],
+            &["Comment", "third"],
+        ]));
+        assert_eq!(settings.value(None, "Comment"), Some("first"));
+        assert_eq!(settings.value(None, "Comment (2)"), Some("second"));
+        assert_eq!(settings.value(None, "Comment (3)"), Some("third"));
+        assert_eq!(settings.value(Some("Gate"), "Compliance"), Some("0.01"));
+        assert_eq!(settings.value(Some("gate"), "Compliance (2)"), Some("0.02"));
+        //errors point at the row the value was read from
+        let error = settings
+            .terminals()
+            .nth(1)
+            .unwrap()
+            .unexpected(&["Compliance (2)"], "a number");
+        assert_eq!(error.cell, Some((4, 2)));
+    }
+}
@@ -1,4 +1,5 @@
-use crate::calamine_helper::MyRange;
+use crate::ingest::IngestError;
+use crate::measurement::settings::Section;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Copy, Clone)]
@@ -14,19 +15,21 @@ pub enum Instrument {
     PMU4,
 }
 
+const LABELS: [&str; 1] = ["Instrument"];
+
 impl crate::Extract for Instrument {
-    fn extract(column: &MyRange) -> Option<Instrument> {
-        match column.it.get((1, 0))?.get_string()? {
-            "SMU1" => Some(Instrument::SMU1),
-            "SMU2" => Some(Instrument::SMU2),
-            "SMU3" => Some(Instrument::SMU3),
-            "SMU4" => Some(Instrument::SMU4),
-            "GNDU" => Some(Instrument::GNDU),
-            "PMU1" => Some(Instrument::PMU1),
-            "PMU2" => Some(Instrument::PMU2),
-            "PMU3" => Some(Instrument::PMU3),
-            "PMU4" => Some(Instrument::PMU4),
-            _ => None,
+    fn extract(column: &Section) -> Result<Instrument, IngestError> {
+        match column.get(&LABELS)? {
+            "SMU1" => Ok(Instrument::SMU1),
+            "SMU2" => Ok(Instrument::SMU2),
+            "SMU3" => Ok(Instrument::SMU3),
+            "SMU4" => Ok(Instrument::SMU4),
+            "GNDU" => Ok(Instrument::GNDU),
+            "PMU1" => Ok(Instrument::PMU1),
+            "PMU2" => Ok(Instrument::PMU2),
+            "PMU3" => Ok(Instrument::PMU3),
+            "PMU4" => Ok(Instrument::PMU4),
+            _ => Err(column.unexpected(&LABELS, "an instrument")),
         }
     }
 }
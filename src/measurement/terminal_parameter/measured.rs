@@ -1,5 +1,4 @@
-use super::super::testparameter::TestType;
-use crate::calamine_helper::MyRange;
+use crate::measurement::settings::Section;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Copy, Clone)]
@@ -13,44 +12,17 @@ pub enum UnitType {
     Current,
 }
 
-#[allow(non_snake_case)]
-struct Unit {
-    Voltage: usize,
-    Current: usize,
-}
-
-#[allow(non_snake_case)]
-struct Positions {
-    Sweeping: Unit,
-    Sampling: Unit,
-}
-
-const OFFSETS: Positions = Positions {
-    Sweeping: Unit {
-        Voltage: 11,
-        Current: 10,
-    },
-    Sampling: Unit {
-        Voltage: 8,
-        Current: 7,
-    },
-};
+const VOLTAGE: [&str; 1] = ["Measure Voltage"];
+const CURRENT: [&str; 1] = ["Measure Current"];
 
-pub fn extract(column: &MyRange, test_type: &TestType, unit: UnitType) -> Option<UnitMeasured> {
-    let extract_at = |offset| match column.it.get((offset, 0))?.get_string()? {
+pub fn extract(column: &Section, unit: UnitType) -> Option<UnitMeasured> {
+    let labels: &[&str] = match unit {
+        UnitType::Voltage => &VOLTAGE,
+        UnitType::Current => &CURRENT,
+    };
+    match column.get(labels).ok()? {
         "Measured" => Some(UnitMeasured::Measured),
         "Programmed" => Some(UnitMeasured::Programmed),
         _ => None,
-    };
-
-    match test_type {
-        TestType::Sweeping => match unit {
-            UnitType::Voltage => extract_at(OFFSETS.Sweeping.Voltage),
-            UnitType::Current => extract_at(OFFSETS.Sweeping.Current),
-        },
-        TestType::Sampling => match unit {
-            UnitType::Voltage => extract_at(OFFSETS.Sampling.Voltage),
-            UnitType::Current => extract_at(OFFSETS.Sampling.Current),
-        },
     }
 }
@@ -1,9 +1,10 @@
-use crate::calamine_helper::MyRange;
+use super::settings::Section;
+use crate::ingest::IngestError;
 use serde::{Deserialize, Serialize};
 
 mod instrument;
 
-mod opmode;
+pub mod opmode;
 
 mod compliance;
 
@@ -11,7 +12,7 @@ mod measured;
 
 mod vrange;
 
-mod crange;
+pub mod crange;
 
 pub mod terminal;
 
@@ -28,21 +29,18 @@ pub struct TerminalParameter {
 }
 
 impl TerminalParameter {
-    pub fn extract(
-        column: &MyRange,
-        test_type: &super::testparameter::TestType,
-    ) -> Option<TerminalParameter> {
+    pub fn extract(column: &Section) -> Result<TerminalParameter, IngestError> {
         use crate::Extract;
-        let terminal = terminal::Terminal::extract(column).unwrap();
-        let instrument = instrument::Instrument::extract(column).unwrap();
-        let operational_mode = opmode::OpMode::extract(column, test_type).unwrap();
-        let compliance = compliance::extract(column, test_type);
-        let voltage = measured::extract(column, test_type, measured::UnitType::Voltage);
-        let voltage_range = vrange::VRange::extract(column, test_type);
-        let current = measured::extract(column, test_type, measured::UnitType::Current);
-        let current_range = crange::CRange::extract(column, test_type);
-
-        Some(TerminalParameter {
+        let terminal = terminal::Terminal::extract(column)?;
+        let instrument = instrument::Instrument::extract(column)?;
+        let operational_mode = opmode::OpMode::extract(column)?;
+        let compliance = compliance::extract(column);
+        let voltage = measured::extract(column, measured::UnitType::Voltage);
+        let voltage_range = vrange::VRange::extract(column);
+        let current = measured::extract(column, measured::UnitType::Current);
+        let current_range = crange::CRange::extract(column);
+
+        Ok(TerminalParameter {
             terminal,
             instrument,
             operational_mode,
@@ -1,4 +1,5 @@
-use crate::calamine_helper::MyRange;
+use crate::ingest::IngestError;
+use crate::measurement::settings::Section;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Copy, Clone)]
@@ -22,70 +23,33 @@ pub enum OpModeType {
     Floating,
 }
 
-struct Bias {
-    bias_or_start: usize,
-    stop: usize,
-    stepsize: usize,
-}
-
-#[allow(non_snake_case)]
-struct Positions {
-    Sweeping: Bias,
-    Sampling: Bias,
-}
-
-const OFFSETS: Positions = Positions {
-    Sweeping: Bias {
-        bias_or_start: 5,
-        stop: 6,
-        stepsize: 7,
-    },
-    Sampling: Bias {
-        bias_or_start: 4,
-        stop: 4,
-        stepsize: 4,
-    },
-};
+const MODE: [&str; 2] = ["Operation Mode", "Operating Mode"];
+const BIAS_OR_START: [&str; 3] = ["Start/Bias", "Bias", "Start"];
+const STOP: [&str; 1] = ["Stop"];
+const STEPSIZE: [&str; 2] = ["Step", "Step Size"];
 
 impl OpMode {
-    pub fn extract(
-        column: &MyRange,
-        test_type: &crate::measurement::testparameter::TestType,
-    ) -> Option<OpMode> {
-        use crate::measurement::testparameter::TestType;
-        let extract_at = |offset| {
-            column
-                .it
-                .get((offset, 0))?
-                .get_string()?
-                .parse::<f64>()
-                .ok()
-        };
-        let op_type = match column.it.get((3, 0))?.get_string()? {
-            "Voltage Bias" => Some(OpModeType::VoltageBias),
-            "Current Bias" => Some(OpModeType::CurrentBias),
-            "Voltage Linear Sweep" => Some(OpModeType::VoltageLinearSweep),
-            "Current Linear Sweep" => Some(OpModeType::CurrentLinearSweep),
-            "Voltage Step" => Some(OpModeType::VoltageStep),
-            "Current Step" => Some(OpModeType::CurrentStep),
-            "Common" => Some(OpModeType::Common),
-            "Floating" => Some(OpModeType::Floating),
-            _ => None,
-        }
-        .unwrap();
-
-        let offset: Bias = match test_type {
-            TestType::Sweeping => OFFSETS.Sweeping,
-            TestType::Sampling => OFFSETS.Sampling,
+    pub fn extract(column: &Section) -> Result<OpMode, IngestError> {
+        let extract_at = |labels: &[&str]| column.get_f64(labels).ok();
+        let op_type = match column.get(&MODE)? {
+            "Voltage Bias" => OpModeType::VoltageBias,
+            "Current Bias" => OpModeType::CurrentBias,
+            "Voltage Linear Sweep" => OpModeType::VoltageLinearSweep,
+            "Current Linear Sweep" => OpModeType::CurrentLinearSweep,
+            "Voltage Step" => OpModeType::VoltageStep,
+            "Current Step" => OpModeType::CurrentStep,
+            "Common" => OpModeType::Common,
+            "Floating" => OpModeType::Floating,
+            _ => return Err(column.unexpected(&MODE, "an operation mode")),
         };
 
         match op_type {
             OpModeType::VoltageBias | OpModeType::CurrentBias => {
-                let bias = extract_at(offset.bias_or_start);
+                let bias = extract_at(&BIAS_OR_START);
                 let start = None;
                 let stop = None;
                 let stepsize = None;
-                Some(OpMode {
+                Ok(OpMode {
                     op_type,
                     bias,
                     start,
@@ -98,7 +62,7 @@ impl OpMode {
                 let start = None;
                 let stop = None;
                 let stepsize = None;
-                Some(OpMode {
+                Ok(OpMode {
                     op_type,
                     bias,
                     start,
@@ -108,10 +72,10 @@ impl OpMode {
             }
             _ => {
                 let bias = None;
-                let start = extract_at(offset.bias_or_start);
-                let stop = extract_at(offset.stop);
-                let stepsize = extract_at(offset.stepsize);
-                Some(OpMode {
+                let start = extract_at(&BIAS_OR_START);
+                let stop = extract_at(&STOP);
+                let stepsize = extract_at(&STEPSIZE);
+                Ok(OpMode {
                     op_type,
                     bias,
                     start,
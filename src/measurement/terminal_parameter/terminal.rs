@@ -1,7 +1,8 @@
-use crate::calamine_helper::MyRange;
+use crate::ingest::IngestError;
+use crate::measurement::settings::Section;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Terminal {
     Gate,
     Drain,
@@ -20,15 +21,16 @@ impl Terminal {
     }
 }
 
+const LABELS: [&str; 1] = ["Device Terminal"];
+
 impl crate::Extract for Terminal {
-    fn extract(column: &MyRange) -> Option<Terminal> {
-        let result = match column.it.get((0, 0))?.get_string()? {
-            "Gate" => Some(Terminal::Gate),
-            "Drain" => Some(Terminal::Drain),
-            "Source" => Some(Terminal::Source),
-            "Bulk" => Some(Terminal::Bulk),
-            _ => None,
-        };
-        result
+    fn extract(column: &Section) -> Result<Terminal, IngestError> {
+        match column.get(&LABELS)? {
+            "Gate" => Ok(Terminal::Gate),
+            "Drain" => Ok(Terminal::Drain),
+            "Source" => Ok(Terminal::Source),
+            "Bulk" => Ok(Terminal::Bulk),
+            _ => Err(column.unexpected(&LABELS, "a terminal")),
+        }
     }
 }
@@ -1,5 +1,6 @@
+use crate::ingest::IngestError;
+use calamine::DataType;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
 pub mod terminal;
 
@@ -9,28 +10,62 @@ pub mod units;
 pub struct TestData {
     pub terminal: terminal::Terminal,
     pub unit: units::Unit,
+    //column header without the series suffix, e.g. "DrainI" or "GM"
+    #[serde(default)]
+    pub name: String,
+    //series number of every vector in data, e.g. 2 for "DrainI(2)"
+    #[serde(default)]
+    pub series: Vec<usize>,
     pub data: Vec<Vec<f64>>,
 }
 
 impl TestData {
+    //data stored without series numbers is counted from 1
+    pub fn series_numbers(&self) -> Vec<usize> {
+        if self.series.len() == self.data.len() {
+            self.series.clone()
+        } else {
+            (1..=self.data.len()).collect()
+        }
+    }
+
     pub fn to_compact(&self) -> TestDataCompact {
         TestDataCompact {
             terminal: self.terminal,
             unit: self.unit,
+            name: self.name.clone(),
             count: self.data.len(),
+            series: self.series_numbers(),
         }
     }
 
-    pub fn from_compact(&self, testdatacompact: &Vec<TestDataCompact>) -> Option<TestData> {
-        let data: Vec<Vec<f64>> = testdatacompact
-            .into_iter()
+    //selects by series number, or by position (count) when no series numbers are given
+    pub fn from_compact(&self, testdatacompact: &[TestDataCompact]) -> Option<TestData> {
+        let numbers = self.series_numbers();
+        let (series, data): (Vec<usize>, Vec<Vec<f64>>) = testdatacompact
+            .iter()
             .filter(|t| t.terminal == self.terminal && t.unit == self.unit)
-            .map(|t| self.data.get(t.count - 1).unwrap_or(&Vec::new()).clone())
-            .collect();
+            .filter(|t| t.name.is_empty() || t.name == self.name)
+            .flat_map(|t| -> Vec<usize> {
+                if t.series.is_empty() {
+                    vec![t.count - 1]
+                } else {
+                    t.series
+                        .iter()
+                        .filter_map(|number| numbers.iter().position(|n| n == number))
+                        .collect()
+                }
+            })
+            .filter_map(|position| {
+                Some((*numbers.get(position)?, self.data.get(position)?.clone()))
+            })
+            .unzip();
         if data.len() > 0 {
             Some(TestData {
                 terminal: self.terminal,
                 unit: self.unit,
+                name: self.name.clone(),
+                series,
                 data,
             })
         } else {
@@ -43,100 +78,79 @@ impl TestData {
 pub struct TestDataCompact {
     pub terminal: terminal::Terminal,
     pub unit: units::Unit,
+    #[serde(default)]
+    pub name: String,
     pub count: usize,
+    #[serde(default)]
+    pub series: Vec<usize>,
 }
 
 impl TestData {
-    pub fn extract(sheet: &crate::calamine_helper::MyRange) -> Vec<TestData> {
+    //known headers map onto a terminal, every other column is kept as a named channel
+    fn channel_of(name: &str) -> (terminal::Terminal, units::Unit) {
         use terminal::Terminal;
         use units::Unit;
-        let columns = (0..sheet.end().1 + 1)
-            .into_iter()
-            .map(|i| sheet.sub_range((0, i), (sheet.end().0, i)));
+        match name {
+            "DrainV" => (Terminal::Drain, Unit::Voltage),
+            "DrainI" => (Terminal::Drain, Unit::Current),
+            "GateV" => (Terminal::Gate, Unit::Voltage),
+            "GateI" => (Terminal::Gate, Unit::Current),
+            "SourceV" => (Terminal::Source, Unit::Voltage),
+            "SourceI" => (Terminal::Source, Unit::Current),
+            "BulkV" => (Terminal::Bulk, Unit::Voltage),
+            "BulkI" => (Terminal::Bulk, Unit::Current),
+            "Time" => (Terminal::Time, Unit::Seconds),
+            other => (Terminal::Other, Unit::from_header(other)),
+        }
+    }
 
-        let hash: &mut HashMap<String, Vec<Vec<f64>>> = &mut HashMap::new();
+    pub fn extract(sheet: &crate::calamine_helper::MyRange) -> Result<Vec<TestData>, IngestError> {
+        let columns = (0..sheet.end().1 + 1).map(|i| sheet.sub_range((0, i), (sheet.end().0, i)));
+
+        let mut result: Vec<TestData> = vec![];
 
         for column in columns {
-            let data: Vec<f64> = column
-                .sub_range((1, 0), (column.end().0, 0))
-                .it
-                .rows()
-                .filter_map(|e| e.into_iter().next().unwrap().get_float())
-                .collect();
+            let mut header: String = match column.it.get((0, 0)) {
+                Some(DataType::Empty) | None => continue,
+                _ => column.get_string((0, 0))?.trim().to_string(),
+            };
+            let data: Vec<f64> = if column.end().0 > 0 {
+                column
+                    .sub_range((1, 0), (column.end().0, 0))
+                    .it
+                    .rows()
+                    .filter_map(|e| e.first().and_then(|cell| cell.get_float()))
+                    .collect()
+            } else {
+                vec![]
+            };
 
-            let mut header: String = column
-                .it
-                .get((0, 0))
-                .unwrap()
-                .get_string()
-                .unwrap()
-                .to_string();
-            if header.contains("(") {
-                header = header.split("(").next().unwrap().to_string();
+            //"DrainI(2)" is the second series of DrainI
+            let mut number: Option<usize> = None;
+            if let Some(index) = header.find('(') {
+                number = header[index + 1..].trim_end_matches(')').parse().ok();
+                header.truncate(index);
             }
 
-            let header_options = vec![
-                "DrainV", "DrainI", "GateV", "GateI", "SourceV", "SourceI", "BulkV", "BulkI",
-                "Time",
-            ];
-            if header_options.contains(&header.as_str()) {
-                if hash.contains_key(&header) {
-                    hash.entry(header).or_insert(vec![vec![]]).push(data);
-                } else {
-                    hash.insert(header, vec![data]);
+            match result.iter_mut().find(|t| t.name == header) {
+                Some(existing) => {
+                    existing
+                        .series
+                        .push(number.unwrap_or(existing.data.len() + 1));
+                    existing.data.push(data);
+                }
+                None => {
+                    let (terminal, unit) = TestData::channel_of(header.as_str());
+                    result.push(TestData {
+                        terminal,
+                        unit,
+                        name: header,
+                        series: vec![number.unwrap_or(1)],
+                        data: vec![data],
+                    })
                 }
             }
         }
-
-        hash.drain()
-            .filter_map(|(header, data)| match header.as_str() {
-                "DrainV" => Some(TestData {
-                    terminal: Terminal::Drain,
-                    unit: units::Unit::Voltage,
-                    data,
-                }),
-                "DrainI" => Some(TestData {
-                    terminal: Terminal::Drain,
-                    unit: Unit::Current,
-                    data,
-                }),
-                "GateV" => Some(TestData {
-                    terminal: Terminal::Gate,
-                    unit: Unit::Voltage,
-                    data,
-                }),
-                "GateI" => Some(TestData {
-                    terminal: Terminal::Gate,
-                    unit: Unit::Current,
-                    data,
-                }),
-                "SourceV" => Some(TestData {
-                    terminal: Terminal::Source,
-                    unit: Unit::Voltage,
-                    data,
-                }),
-                "SourceI" => Some(TestData {
-                    terminal: Terminal::Source,
-                    unit: Unit::Current,
-                    data,
-                }),
-                "BulkV" => Some(TestData {
-                    terminal: Terminal::Bulk,
-                    unit: Unit::Voltage,
-                    data,
-                }),
-                "BulkI" => Some(TestData {
-                    terminal: Terminal::Bulk,
-                    unit: Unit::Current,
-                    data,
-                }),
-                "Time" => Some(TestData {
-                    terminal: Terminal::Time,
-                    unit: Unit::Seconds,
-                    data,
-                }),
-                _ => None,
-            })
-            .collect()
+        Ok(result)
     }
 }
@@ -7,6 +7,8 @@ pub enum Terminal {
     Source,
     Bulk,
     Time,
+    //channel that is not measured on one of the terminals, e.g. a formula column
+    Other,
 }
 
 impl Terminal {
@@ -17,6 +19,7 @@ impl Terminal {
             Terminal::Gate => "g",
             Terminal::Source => "s",
             Terminal::Time => "T",
+            Terminal::Other => "",
         }
     }
 }
@@ -5,6 +5,7 @@ pub enum Unit {
     Voltage,
     Current,
     Seconds,
+    Other,
 }
 
 impl Unit {
@@ -13,6 +14,25 @@ impl Unit {
             Unit::Voltage => "V",
             Unit::Current => "I",
             Unit::Seconds => "T",
+            Unit::Other => "",
+        }
+    }
+
+    //unit of a column that is not one of the known terminals, e.g. "SMU5I", "Vout [V]" or "GM"
+    pub fn from_header(header: &str) -> Unit {
+        let header = header.trim();
+        let explicit = header
+            .rfind('[')
+            .and_then(|start| header[start + 1..].strip_suffix(']'));
+        match explicit {
+            Some("V") => Unit::Voltage,
+            Some("A") => Unit::Current,
+            Some("s") => Unit::Seconds,
+            Some(_) => Unit::Other,
+            None if header.ends_with("Time") => Unit::Seconds,
+            None if header.ends_with('I') => Unit::Current,
+            None if header.ends_with('V') => Unit::Voltage,
+            None => Unit::Other,
         }
     }
 }
@@ -1,4 +1,5 @@
-use crate::calamine_helper::MyRange;
+use super::settings::Section;
+use crate::ingest::IngestError;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -46,58 +47,41 @@ impl std::fmt::Display for MeasurementSpeed {
     }
 }
 
-impl crate::Extract for TestParameter {
-    fn extract(sheet: &MyRange) -> Option<TestParameter> {
-        match sheet.it.get((4, 1))?.get_string()? {
-            "Sweeping" => {
-                let test_type = TestType::Sweeping;
-                let measurement_speed = match sheet.it.get((5, 1))?.get_string()? {
-                    "Fast" => MeasurementSpeed::Fast,
-                    "Normal" => MeasurementSpeed::Normal,
-                    "Quiet" => MeasurementSpeed::Quiet,
-                    _ => MeasurementSpeed::Custom,
-                };
-                let ad_aperture = None;
-                let filter_factor = None;
-                let interval_time = None;
-                let sweep_delay_time = sheet.it.get((6, 1))?.get_string()?.parse::<f64>().ok();
-                let hold_time = sheet.it.get((7, 1))?.get_string()?.parse::<f64>().ok()?;
+const MODE: [&str; 2] = ["Mode", "Test Mode"];
+const SPEED: [&str; 1] = ["Speed"];
+const SWEEP_DELAY: [&str; 2] = ["Sweep Delay", "Sweep Delay Time"];
+const INTERVAL: [&str; 3] = ["Interval", "Interval Time", "Sample Interval"];
+const HOLD_TIME: [&str; 1] = ["Hold Time"];
+const AD_APERTURE: [&str; 3] = ["A/D Aperture Time", "A/D Aperture", "AD Aperture"];
+const FILTER_FACTOR: [&str; 1] = ["Filter Factor"];
 
-                Some(TestParameter {
-                    test_type,
-                    measurement_speed,
-                    ad_aperture,
-                    filter_factor,
-                    interval_time,
-                    sweep_delay_time,
-                    hold_time,
-                })
-            }
-            "Sampling" => {
-                let test_type = TestType::Sampling;
-                let measurement_speed = match sheet.it.get((5, 1))?.get_string()? {
-                    "Fast" => MeasurementSpeed::Fast,
-                    "Normal" => MeasurementSpeed::Normal,
-                    "Quiet" => MeasurementSpeed::Quiet,
-                    _ => MeasurementSpeed::Custom,
-                };
-                let ad_aperture = None;
-                let filter_factor = None;
-                let interval_time = sheet.it.get((6, 1))?.get_string()?.parse::<f64>().ok();
-                let sweep_delay_time = None;
-                let hold_time = sheet.it.get((7, 1))?.get_string()?.parse::<f64>().ok()?;
+impl crate::Extract for TestParameter {
+    fn extract(settings: &Section) -> Result<TestParameter, IngestError> {
+        let measurement_speed = match settings.get(&SPEED)? {
+            "Fast" => MeasurementSpeed::Fast,
+            "Normal" => MeasurementSpeed::Normal,
+            "Quiet" => MeasurementSpeed::Quiet,
+            _ => MeasurementSpeed::Custom,
+        };
+        let test_type = match settings.get(&MODE)? {
+            "Sweeping" => TestType::Sweeping,
+            "Sampling" => TestType::Sampling,
+            _ => return Err(settings.unexpected(&MODE, "'Sweeping' or 'Sampling'")),
+        };
+        let ad_aperture = settings.get_f64(&AD_APERTURE).ok();
+        let filter_factor = settings.get_f64(&FILTER_FACTOR).ok();
+        let interval_time = settings.get_f64(&INTERVAL).ok();
+        let sweep_delay_time = settings.get_f64(&SWEEP_DELAY).ok();
+        let hold_time = settings.get_f64(&HOLD_TIME)?;
 
-                Some(TestParameter {
-                    test_type,
-                    measurement_speed,
-                    ad_aperture,
-                    filter_factor,
-                    interval_time,
-                    sweep_delay_time,
-                    hold_time,
-                })
-            }
-            _ => None,
-        }
+        Ok(TestParameter {
+            test_type,
+            measurement_speed,
+            ad_aperture,
+            filter_factor,
+            interval_time,
+            sweep_delay_time,
+            hold_time,
+        })
     }
 }
@@ -1,4 +1,5 @@
-use crate::calamine_helper::MyRange;
+use super::settings::Section;
+use crate::ingest::IngestError;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash)]
@@ -28,7 +29,7 @@ pub struct Date {
     pub day: u8,
 }
 
-const OFFSETS: (usize, usize) = (9, 1);
+const LABELS: [&str; 2] = ["Last Executed", "Test Time"];
 
 impl std::fmt::Display for Date {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -46,49 +47,36 @@ impl From<TimeStamp> for Date {
     }
 }
 
-impl crate::Extract for TimeStamp {
-    fn extract(sheet: &MyRange) -> Option<TimeStamp> {
-        let raw_string: &str = sheet
-            .it
-            .get(OFFSETS)?
-            .get_string()
-            .expect("Time stamp extraction failure");
+impl TimeStamp {
+    //"MM/DD/YYYY hh:mm:ss" as written by KITE, "YYYY-MM-DD" dates and AM/PM times are accepted too
+    pub fn parse(raw_string: &str) -> Option<TimeStamp> {
         let mut split_string = raw_string.split_ascii_whitespace();
-        let mut date = split_string.next().unwrap().split('/');
+        let date_string = split_string.next()?;
 
-        //keep this order for the Yankees
-        let month: u8 = date
-            .next()
-            .unwrap_or("0")
-            .parse()
-            .expect("error parsing time stamp");
-        let day: u8 = date
-            .next()
-            .unwrap_or("0")
-            .parse()
-            .expect("error parsing time stamp");
-        let year: u16 = date
-            .next()
-            .unwrap_or("0")
-            .parse()
-            .expect("error parsing time stamp");
+        let (year, month, day): (u16, u8, u8) = if date_string.contains('-') {
+            let mut date = date_string.split('-');
+            let year = date.next()?.parse().ok()?;
+            let month = date.next()?.parse().ok()?;
+            let day = date.next()?.parse().ok()?;
+            (year, month, day)
+        } else {
+            //keep this order for the Yankees
+            let mut date = date_string.split('/');
+            let month = date.next()?.parse().ok()?;
+            let day = date.next()?.parse().ok()?;
+            let year = date.next()?.parse().ok()?;
+            (year, month, day)
+        };
 
-        let mut time = split_string.next().unwrap().split(':');
-        let hour: u8 = time
-            .next()
-            .unwrap_or("0")
-            .parse()
-            .expect("error parsing time stamp");
-        let minute: u8 = time
-            .next()
-            .unwrap_or("0")
-            .parse()
-            .expect("error parsing time stamp");
-        let second: u8 = time
-            .next()
-            .unwrap_or("0")
-            .parse()
-            .expect("error parsing time stamp");
+        let mut time = split_string.next()?.split(':');
+        let mut hour: u8 = time.next().unwrap_or("0").parse().ok()?;
+        let minute: u8 = time.next().unwrap_or("0").parse().ok()?;
+        let second: u8 = time.next().unwrap_or("0").parse().ok()?;
+        match split_string.next() {
+            Some("PM") if hour < 12 => hour += 12,
+            Some("AM") if hour == 12 => hour = 0,
+            _ => {}
+        }
         Some(TimeStamp {
             year,
             month,
@@ -99,3 +87,10 @@ impl crate::Extract for TimeStamp {
         })
     }
 }
+
+impl crate::Extract for TimeStamp {
+    fn extract(settings: &Section) -> Result<TimeStamp, IngestError> {
+        let raw_string: &str = settings.get(&LABELS)?;
+        TimeStamp::parse(raw_string).ok_or_else(|| settings.unexpected(&LABELS, "a time stamp"))
+    }
+}
@@ -0,0 +1,98 @@
+use super::ParseError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    //a name, number, date or value like "GF22", "1um" or "2019-12-01", typed by the parser
+    Word(String),
+    //"..." for values and setting labels with spaces
+    Quoted(String),
+    Operator(&'static str),
+    Open,
+    Close,
+    Comma,
+    Colon,
+    End,
+}
+
+//a token and the position of its first character
+#[derive(Debug, Clone)]
+pub struct Spanned {
+    pub token: Token,
+    pub position: usize,
+}
+
+const OPERATORS: [&str; 8] = ["==", "!=", "<=", ">=", "=", "<", ">", "~"];
+
+fn is_word(c: char) -> bool {
+    c.is_alphanumeric() || "_.#+-°µ".contains(c)
+}
+
+pub fn tokens(source: &str) -> Result<Vec<Spanned>, ParseError> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let position = i;
+        let token = if c.is_whitespace() {
+            i += 1;
+            continue;
+        } else if c == '(' {
+            i += 1;
+            Token::Open
+        } else if c == ')' {
+            i += 1;
+            Token::Close
+        } else if c == ',' {
+            i += 1;
+            Token::Comma
+        } else if c == ':' {
+            i += 1;
+            Token::Colon
+        } else if c == '"' {
+            let mut text = String::new();
+            i += 1;
+            loop {
+                match chars.get(i) {
+                    None => return Err(ParseError::new(position, "unterminated quote")),
+                    Some('"') => break,
+                    Some('\\') if chars.get(i + 1).is_some() => {
+                        text.push(chars[i + 1]);
+                        i += 2;
+                    }
+                    Some(c) => {
+                        text.push(*c);
+                        i += 1;
+                    }
+                }
+            }
+            i += 1;
+            Token::Quoted(text)
+        } else if let Some(operator) = OPERATORS.iter().find(|operator| {
+            operator
+                .chars()
+                .enumerate()
+                .all(|(n, o)| chars.get(i + n) == Some(&o))
+        }) {
+            i += operator.chars().count();
+            Token::Operator(operator)
+        } else if is_word(c) {
+            let start = i;
+            while i < chars.len() && is_word(chars[i]) {
+                i += 1;
+            }
+            Token::Word(chars[start..i].iter().collect())
+        } else {
+            return Err(ParseError::new(
+                position,
+                format!("unexpected character '{}'", c),
+            ));
+        };
+        tokens.push(Spanned { token, position });
+    }
+    tokens.push(Spanned {
+        token: Token::End,
+        position: chars.len(),
+    });
+    Ok(tokens)
+}
@@ -0,0 +1,212 @@
+use crate::measurement::MeasurementCompact;
+use serde::{Deserialize, Serialize};
+
+mod lexer;
+
+mod parser;
+
+//a filter written as text, e.g. `wafer in (GF22, MINOXG) and W >= 1um and not T = 293K`
+#[derive(Debug, Clone, PartialEq)]
+pub enum Query {
+    And(Box<Query>, Box<Query>),
+    Or(Box<Query>, Box<Query>),
+    Not(Box<Query>),
+    Compare(Field, Op, Value),
+    //`field in (a, b)`
+    OneOf(Field, Vec<Value>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Field {
+    Process,
+    Die,
+    //nm
+    Width,
+    //nm
+    Length,
+    //K
+    Temperature,
+    Date,
+    Sheet,
+    TestType,
+    Speed,
+    File,
+    Id,
+    Alias,
+    //a row of the "Settings" sheet, of a terminal column when given
+    Setting {
+        terminal: Option<String>,
+        label: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    //contains the text, ignoring case
+    Contains,
+}
+
+//values are converted to the unit of their field while parsing
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Number(f64),
+    //yyyymmdd
+    Date(u32),
+    Text(String),
+    //settings are compared by value when both sides are numbers
+    Setting { text: String, number: Option<f64> },
+}
+
+//where and why a query cannot be read, the position counts characters from 0
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParseError {
+    pub position: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    fn new<S: Into<String>>(position: usize, message: S) -> ParseError {
+        ParseError {
+            position,
+            message: message.into(),
+        }
+    }
+
+    //the query with a marker under the position of the error
+    pub fn show(&self, source: &str) -> String {
+        format!(
+            "{}\n{}^ {}",
+            source,
+            " ".repeat(self.position),
+            self.message
+        )
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+fn same_number(found: f64, wanted: f64) -> bool {
+    (found - wanted).abs() <= 1e-9 * wanted.abs().max(found.abs())
+}
+
+fn compare<T: PartialOrd>(found: T, op: Op, wanted: T) -> bool {
+    match op {
+        Op::Eq => found == wanted,
+        Op::Ne => found != wanted,
+        Op::Lt => found < wanted,
+        Op::Le => found <= wanted,
+        Op::Gt => found > wanted,
+        Op::Ge => found >= wanted,
+        Op::Contains => false,
+    }
+}
+
+fn compare_numbers(found: f64, op: Op, wanted: f64) -> bool {
+    match op {
+        Op::Eq => same_number(found, wanted),
+        Op::Ne => !same_number(found, wanted),
+        Op::Le => found <= wanted || same_number(found, wanted),
+        Op::Ge => found >= wanted || same_number(found, wanted),
+        op => compare(found, op, wanted),
+    }
+}
+
+fn compare_texts(found: &str, op: Op, wanted: &str) -> bool {
+    match op {
+        Op::Eq => found.eq_ignore_ascii_case(wanted),
+        Op::Ne => !found.eq_ignore_ascii_case(wanted),
+        Op::Contains => found
+            .to_lowercase()
+            .contains(wanted.to_lowercase().as_str()),
+        op => compare(found, op, wanted),
+    }
+}
+
+//the value of a field, None when the run does not have it
+fn field_of(field: &Field, measurement: &MeasurementCompact) -> Option<Value> {
+    let device = &measurement.device;
+    let time_stamp = measurement.test_time_stamp;
+    Some(match field {
+        Field::Process => Value::Text(device.wafer.as_ref()?.to_string()),
+        Field::Die => Value::Text(device.die.clone()?),
+        Field::Width => Value::Number(device.width?),
+        Field::Length => Value::Number(device.length?),
+        Field::Temperature => Value::Number(device.temperature? as f64),
+        Field::Date => Value::Date(
+            time_stamp.year as u32 * 10000 + time_stamp.month as u32 * 100 + time_stamp.day as u32,
+        ),
+        Field::Sheet => Value::Text(measurement.sheet_name.clone()),
+        Field::TestType => Value::Text(measurement.test_parameter.test_type.to_string()),
+        Field::Speed => Value::Text(measurement.test_parameter.measurement_speed.to_string()),
+        Field::File => Value::Text(measurement.file_path.clone()),
+        Field::Id => Value::Text(measurement.id.clone()),
+        Field::Alias => Value::Text(measurement.alias.clone()),
+        Field::Setting { terminal, label } => Value::Text(
+            measurement
+                .settings
+                .value(terminal.as_deref(), label.as_str())?
+                .to_string(),
+        ),
+    })
+}
+
+fn matches_value(found: &Value, op: Op, wanted: &Value) -> bool {
+    match (found, wanted) {
+        (Value::Number(found), Value::Number(wanted)) => compare_numbers(*found, op, *wanted),
+        (Value::Date(found), Value::Date(wanted)) => compare(found, op, wanted),
+        (Value::Text(found), Value::Text(wanted)) => compare_texts(found, op, wanted),
+        (Value::Text(found), Value::Setting { text, number }) => {
+            match (found.trim().parse::<f64>(), number) {
+                (Ok(found), Some(wanted)) if op != Op::Contains => {
+                    compare_numbers(found, op, *wanted)
+                }
+                _ => compare_texts(found.trim(), op, text),
+            }
+        }
+        _ => false,
+    }
+}
+
+impl Query {
+    pub fn parse(source: &str) -> Result<Query, ParseError> {
+        parser::parse(source)
+    }
+
+    //a run without the value of a field matches no comparison of that field
+    pub fn matches(&self, measurement: &MeasurementCompact) -> bool {
+        match self {
+            Query::And(left, right) => left.matches(measurement) && right.matches(measurement),
+            Query::Or(left, right) => left.matches(measurement) || right.matches(measurement),
+            Query::Not(query) => !query.matches(measurement),
+            Query::Compare(field, op, value) => {
+                field_of(field, measurement).is_some_and(|found| matches_value(&found, *op, value))
+            }
+            Query::OneOf(field, values) => field_of(field, measurement).is_some_and(|found| {
+                values
+                    .iter()
+                    .any(|value| matches_value(&found, Op::Eq, value))
+            }),
+        }
+    }
+
+    //the matching runs in the order of the library, without copying them
+    pub fn select<'a>(
+        &self,
+        measurements: &'a [MeasurementCompact],
+    ) -> Vec<&'a MeasurementCompact> {
+        measurements
+            .iter()
+            .filter(|measurement| self.matches(measurement))
+            .collect()
+    }
+}
@@ -0,0 +1,445 @@
+use super::lexer::{self, Spanned, Token};
+use super::{Field, Op, ParseError, Query, Value};
+use crate::measurement::device::Device;
+
+//what the values of a field are read as
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Text,
+    Length,
+    Temperature,
+    Date,
+    Setting,
+}
+
+const FIELDS: &str =
+    "wafer, die, W, L, T, date, sheet, type, speed, file, id, alias or a \"setting\"";
+
+struct Parser {
+    tokens: Vec<Spanned>,
+    next: usize,
+}
+
+//query := or
+//or := and ("or" and)*
+//and := not ("and" not)*
+//not := "not" not | "(" or ")" | comparison
+//comparison := field operator value | field ["not"] "in" "(" value ("," value)* ")"
+//field := name | "label" | terminal ":" label
+pub fn parse(source: &str) -> Result<Query, ParseError> {
+    let mut parser = Parser {
+        tokens: lexer::tokens(source)?,
+        next: 0,
+    };
+    let query = parser.or()?;
+    match parser.peek() {
+        Token::End => Ok(query),
+        _ => Err(parser.error("expected 'and', 'or' or the end of the query")),
+    }
+}
+
+fn number_prefix(word: &str) -> Option<(f64, &str)> {
+    if !word.starts_with(|c: char| c.is_ascii_digit() || c == '-' || c == '+' || c == '.') {
+        return None;
+    }
+    word.char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(word.len()))
+        .rev()
+        .find_map(|end| {
+            word[..end]
+                .parse::<f64>()
+                .ok()
+                .filter(|number| number.is_finite())
+                .map(|number| (number, &word[end..]))
+        })
+}
+
+//"1ms", "10mV" or "1E-3" in the base unit
+fn si_number(word: &str) -> Option<f64> {
+    const PREFIXES: [(&str, f64); 10] = [
+        ("", 1.0),
+        ("f", 1e-15),
+        ("p", 1e-12),
+        ("n", 1e-9),
+        ("u", 1e-6),
+        ("µ", 1e-6),
+        ("m", 1e-3),
+        ("k", 1e3),
+        ("M", 1e6),
+        ("G", 1e9),
+    ];
+    const UNITS: [&str; 7] = ["", "s", "V", "A", "Hz", "F", "Ohm"];
+    let (number, unit) = number_prefix(word)?;
+    PREFIXES.iter().find_map(|(prefix, factor)| {
+        unit.strip_prefix(prefix)
+            .filter(|rest| UNITS.contains(rest))
+            .map(|_| number * factor)
+    })
+}
+
+//nm, the unit is required, "W >= 1" could mean 1nm as well as 1um
+fn length(word: &str) -> Option<f64> {
+    match number_prefix(word)? {
+        (_, "") => None,
+        _ => Device::from_metric(word),
+    }
+}
+
+//whole K like the temperatures of the runs
+fn temperature(word: &str) -> Option<f64> {
+    let (number, unit) = number_prefix(word)?;
+    let kelvin = match unit {
+        "" | "K" | "k" => number,
+        "mK" => number / 1000.0,
+        "C" | "°C" | "degC" => number + 273.15,
+        _ => return None,
+    };
+    Some(kelvin.round())
+}
+
+//"2019-12-01" or "20191201" as yyyymmdd
+fn date(word: &str) -> Option<u32> {
+    let digits: String = word.chars().filter(|c| *c != '-').collect();
+    let dashes = word.len() - digits.len();
+    if digits.len() != 8 || !(dashes == 0 || (dashes == 2 && word.len() == 10)) {
+        return None;
+    }
+    let date = digits.parse::<u32>().ok()?;
+    let (month, day) = (date / 100 % 100, date % 100);
+    ((1..=12).contains(&month) && (1..=31).contains(&day)).then_some(date)
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.next].token
+    }
+
+    fn position(&self) -> usize {
+        self.tokens[self.next].position
+    }
+
+    fn advance(&mut self) -> Spanned {
+        let spanned = self.tokens[self.next].clone();
+        if spanned.token != Token::End {
+            self.next += 1;
+        }
+        spanned
+    }
+
+    fn error(&self, message: &str) -> ParseError {
+        ParseError::new(self.position(), message)
+    }
+
+    fn keyword(&self, keyword: &str) -> bool {
+        match self.peek() {
+            Token::Word(word) => word.eq_ignore_ascii_case(keyword),
+            _ => false,
+        }
+    }
+
+    fn or(&mut self) -> Result<Query, ParseError> {
+        let mut query = self.and()?;
+        while self.keyword("or") {
+            self.advance();
+            query = Query::Or(Box::new(query), Box::new(self.and()?));
+        }
+        Ok(query)
+    }
+
+    fn and(&mut self) -> Result<Query, ParseError> {
+        let mut query = self.not()?;
+        while self.keyword("and") {
+            self.advance();
+            query = Query::And(Box::new(query), Box::new(self.not()?));
+        }
+        Ok(query)
+    }
+
+    fn not(&mut self) -> Result<Query, ParseError> {
+        if self.keyword("not") {
+            self.advance();
+            return Ok(Query::Not(Box::new(self.not()?)));
+        }
+        if *self.peek() == Token::Open {
+            let open = self.advance().position;
+            let query = self.or()?;
+            if *self.peek() != Token::Close {
+                return Err(self.error(&format!(
+                    "expected ')' to close the '(' at position {}",
+                    open
+                )));
+            }
+            self.advance();
+            return Ok(query);
+        }
+        self.comparison()
+    }
+
+    fn field(&mut self) -> Result<(Field, Kind, String), ParseError> {
+        let spanned = self.advance();
+        let name = match spanned.token {
+            Token::Quoted(label) => {
+                return Ok((
+                    Field::Setting {
+                        terminal: None,
+                        label: label.clone(),
+                    },
+                    Kind::Setting,
+                    format!("\"{}\"", label),
+                ))
+            }
+            Token::Word(name) => name,
+            _ => {
+                return Err(ParseError::new(
+                    spanned.position,
+                    format!("expected a field: {}", FIELDS),
+                ))
+            }
+        };
+        if *self.peek() == Token::Colon {
+            self.advance();
+            let label = match self.advance().token {
+                Token::Word(label) | Token::Quoted(label) => label,
+                _ => {
+                    return Err(ParseError::new(
+                        self.tokens[self.next - 1].position,
+                        "expected the label of a setting after ':'",
+                    ))
+                }
+            };
+            let shown = format!("{}:\"{}\"", name, label);
+            return Ok((
+                Field::Setting {
+                    terminal: Some(name),
+                    label,
+                },
+                Kind::Setting,
+                shown,
+            ));
+        }
+        let (field, kind) = match name.to_lowercase().as_str() {
+            "wafer" | "process" => (Field::Process, Kind::Text),
+            "die" => (Field::Die, Kind::Text),
+            "w" | "width" => (Field::Width, Kind::Length),
+            "l" | "length" => (Field::Length, Kind::Length),
+            "t" | "temp" | "temperature" => (Field::Temperature, Kind::Temperature),
+            "date" => (Field::Date, Kind::Date),
+            "sheet" => (Field::Sheet, Kind::Text),
+            "type" | "test_type" => (Field::TestType, Kind::Text),
+            "speed" | "measurement_speed" => (Field::Speed, Kind::Text),
+            "file" => (Field::File, Kind::Text),
+            "id" => (Field::Id, Kind::Text),
+            "alias" => (Field::Alias, Kind::Text),
+            _ => {
+                return Err(ParseError::new(
+                    spanned.position,
+                    format!("unknown field '{}', expected {}", name, FIELDS),
+                ))
+            }
+        };
+        Ok((field, kind, name))
+    }
+
+    fn comparison(&mut self) -> Result<Query, ParseError> {
+        let (field, kind, name) = self.field()?;
+        let negated = self.keyword("not");
+        if negated {
+            self.advance();
+            if !self.keyword("in") {
+                return Err(self.error("expected 'in' after 'not'"));
+            }
+        }
+        if self.keyword("in") {
+            self.advance();
+            if *self.peek() != Token::Open {
+                return Err(self.error("expected '(' and a list of values"));
+            }
+            self.advance();
+            let mut values = vec![self.value(kind, Op::Eq, &name)?];
+            while *self.peek() == Token::Comma {
+                self.advance();
+                values.push(self.value(kind, Op::Eq, &name)?);
+            }
+            if *self.peek() != Token::Close {
+                return Err(self.error("expected ',' or ')'"));
+            }
+            self.advance();
+            let query = Query::OneOf(field, values);
+            return Ok(if negated {
+                Query::Not(Box::new(query))
+            } else {
+                query
+            });
+        }
+        let op = match self.peek() {
+            Token::Operator(operator) => match *operator {
+                "=" | "==" => Op::Eq,
+                "!=" => Op::Ne,
+                "<" => Op::Lt,
+                "<=" => Op::Le,
+                ">" => Op::Gt,
+                ">=" => Op::Ge,
+                _ => Op::Contains,
+            },
+            _ => {
+                return Err(self.error(&format!(
+                    "expected an operator (=, !=, <, <=, >, >=, ~ or in) after {}",
+                    name
+                )))
+            }
+        };
+        let ordering = !matches!(op, Op::Eq | Op::Ne | Op::Contains);
+        if kind == Kind::Text && ordering {
+            return Err(self.error(&format!("{} is text and cannot be ordered", name)));
+        }
+        if op == Op::Contains && kind != Kind::Text && kind != Kind::Setting {
+            return Err(self.error("'~' only applies to text"));
+        }
+        self.advance();
+        let value = self.value(kind, op, &name)?;
+        Ok(Query::Compare(field, op, value))
+    }
+
+    fn value(&mut self, kind: Kind, op: Op, name: &str) -> Result<Value, ParseError> {
+        let spanned = self.advance();
+        let word = match spanned.token {
+            Token::Word(word) | Token::Quoted(word) => word,
+            _ => {
+                return Err(ParseError::new(
+                    spanned.position,
+                    format!("expected a value for {}", name),
+                ))
+            }
+        };
+        let position = spanned.position;
+        let invalid = |expected: &str| {
+            ParseError::new(
+                position,
+                format!("'{}' is not {} for {}", word, expected, name),
+            )
+        };
+        match kind {
+            Kind::Text => Ok(Value::Text(word.clone())),
+            Kind::Length => length(&word)
+                .map(Value::Number)
+                .ok_or_else(|| invalid("a length with its unit, like 500nm or 1um")),
+            Kind::Temperature => temperature(&word)
+                .map(Value::Number)
+                .ok_or_else(|| invalid("a temperature like 293K or 20C")),
+            Kind::Date => date(&word)
+                .map(Value::Date)
+                .ok_or_else(|| invalid("a date like 2019-12-01")),
+            Kind::Setting => {
+                let number = si_number(&word);
+                if number.is_none() && !matches!(op, Op::Eq | Op::Ne | Op::Contains) {
+                    return Err(invalid("a number"));
+                }
+                Ok(Value::Setting {
+                    text: word.clone(),
+                    number,
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compare(field: Field, op: Op, value: Value) -> Box<Query> {
+        Box::new(Query::Compare(field, op, value))
+    }
+
+    #[test]
+    fn reads_the_example_of_the_request() {
+        let query =
+            parse("wafer in (GF22, MINOXG) and W >= 1um and L < 500nm and T != 293K and date > 2019-12-01")
+                .unwrap();
+        let wafers = Box::new(Query::OneOf(
+            Field::Process,
+            vec![
+                Value::Text(String::from("GF22")),
+                Value::Text(String::from("MINOXG")),
+            ],
+        ));
+        let width = compare(Field::Width, Op::Ge, Value::Number(1000.0));
+        let length = compare(Field::Length, Op::Lt, Value::Number(500.0));
+        let temperature = compare(Field::Temperature, Op::Ne, Value::Number(293.0));
+        let date = compare(Field::Date, Op::Gt, Value::Date(20191201));
+        let expected = Query::And(
+            Box::new(Query::And(
+                Box::new(Query::And(Box::new(Query::And(wafers, width)), length)),
+                temperature,
+            )),
+            date,
+        );
+        assert_eq!(query, expected);
+    }
+
+    #[test]
+    fn reads_settings_with_si_prefixes() {
+        let query = parse("\"Sweep Delay\" <= 1ms or not drain:Compliance >= 100mA").unwrap();
+        let delay = compare(
+            Field::Setting {
+                terminal: None,
+                label: String::from("Sweep Delay"),
+            },
+            Op::Le,
+            Value::Setting {
+                text: String::from("1ms"),
+                number: Some(1e-3),
+            },
+        );
+        let compliance = compare(
+            Field::Setting {
+                terminal: Some(String::from("drain")),
+                label: String::from("Compliance"),
+            },
+            Op::Ge,
+            Value::Setting {
+                text: String::from("100mA"),
+                number: Some(0.1),
+            },
+        );
+        assert_eq!(query, Query::Or(delay, Box::new(Query::Not(compliance))));
+    }
+
+    //the position counts characters, "µ" is one
+    #[test]
+    fn errors_point_at_their_cause() {
+        let errors = [
+            ("W >= 1", 5, "'1' is not a length"),
+            ("L < 0.5 um", 4, "'0.5' is not a length"),
+            ("W >= -infum", 5, "is not a length"),
+            ("T > +nan", 4, "'+nan' is not a temperature"),
+            ("\"Sweep Delay\" < -inf", 16, "'-inf' is not a number"),
+            ("date = 2019-13-01", 7, "is not a date"),
+            ("size = 3", 0, "unknown field 'size'"),
+            ("wafer = GF22 and", 16, "expected a field"),
+            ("wafer = GF22 die = A1", 13, "expected 'and', 'or'"),
+            ("(wafer = GF22", 13, "to close the '(' at position 0"),
+            ("wafer < GF22", 6, "cannot be ordered"),
+            ("W ~ 1um", 2, "'~' only applies to text"),
+            ("wafer in GF22", 9, "expected '('"),
+            ("wafer not GF22", 10, "expected 'in' after 'not'"),
+            ("wafer = \"GF22", 8, "unterminated quote"),
+            (
+                "W >= 1µm and L = 1um & T = 4K",
+                21,
+                "unexpected character '&'",
+            ),
+            ("W >= 1µm and x = 1", 13, "unknown field 'x'"),
+        ];
+        for (source, position, message) in errors.iter() {
+            let error = parse(source).unwrap_err();
+            assert!(
+                error.position == *position && error.message.contains(message),
+                "{}: {:?}",
+                source,
+                error
+            );
+        }
+    }
+}
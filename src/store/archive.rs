@@ -0,0 +1,65 @@
+use super::Store;
+use crate::database::Database;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::fs::{self, File};
+use std::io;
+use std::path::Path;
+
+//a library in a single file, a gzip compressed tar of result.json, data/<id>.bin and with
+//`sources` the workbooks the runs were read from under sources/<path>
+//returns the number of workbooks that were added
+pub fn write(
+    path: &Path,
+    database: &Database,
+    store: &Store,
+    sources: Option<&Path>,
+) -> io::Result<usize> {
+    let mut archive =
+        tar::Builder::new(GzEncoder::new(File::create(path)?, Compression::default()));
+    let append = |archive: &mut tar::Builder<_>, name: &str, bytes: &[u8]| {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(bytes.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        archive.append_data(&mut header, name, bytes)
+    };
+    append(
+        &mut archive,
+        "result.json",
+        serde_json::to_string(database)?.as_bytes(),
+    )?;
+    for measurement in database.measurements.iter() {
+        if let Some(blob) = store.blob(&measurement.id)? {
+            append(
+                &mut archive,
+                format!("data/{}.bin", measurement.id).as_str(),
+                &blob,
+            )?;
+        }
+    }
+    let mut added = 0;
+    if let Some(input_dir) = sources {
+        for file in database.files.iter() {
+            match fs::read(input_dir.join(&file.path)) {
+                Ok(bytes) => {
+                    append(
+                        &mut archive,
+                        format!("sources/{}", file.path).as_str(),
+                        &bytes,
+                    )?;
+                    added += 1;
+                }
+                Err(e) => eprintln!("{} is not added: {}", file.path, e),
+            }
+        }
+    }
+    archive.into_inner()?.finish()?;
+    Ok(added)
+}
+
+pub fn unpack(path: &Path, dir: &Path) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    tar::Archive::new(GzDecoder::new(File::open(path)?)).unpack(dir)
+}
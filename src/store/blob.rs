@@ -0,0 +1,406 @@
+use crate::measurement::testdata::{terminal::Terminal, units::Unit, TestData};
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+use std::convert::TryInto;
+use std::io::{self, Read, Write};
+
+const MAGIC: &[u8; 4] = b"KDAT";
+
+//1: json header and plain f64 samples
+//2: json header, one encoded column per vector and a crc32 of the whole blob
+const VERSION: u8 = 2;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Precision {
+    F64,
+    //halves the size, about 7 significant digits are kept
+    F32,
+}
+
+//how new blobs are written, blobs of every encoding can be read
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+pub struct Encoding {
+    #[serde(default = "full_precision")]
+    pub precision: Precision,
+    //differences of the bit patterns of neighbouring samples, lossless
+    #[serde(default = "enabled")]
+    pub delta: bool,
+    //byte planes of the column deflated
+    #[serde(default = "enabled")]
+    pub compress: bool,
+}
+
+impl Default for Encoding {
+    fn default() -> Self {
+        Encoding {
+            precision: Precision::F64,
+            delta: true,
+            compress: true,
+        }
+    }
+}
+
+fn full_precision() -> Precision {
+    Precision::F64
+}
+
+fn enabled() -> bool {
+    true
+}
+
+//layout of one channel, the columns follow the header in the same order
+#[derive(Debug, Serialize, Deserialize)]
+struct Channel {
+    terminal: Terminal,
+    unit: Unit,
+    name: String,
+    series: Vec<usize>,
+    columns: Vec<Column>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Column {
+    samples: usize,
+    precision: Precision,
+    delta: bool,
+    compressed: bool,
+    //size in the blob
+    bytes: usize,
+}
+
+//version 1 header
+#[derive(Debug, Deserialize)]
+struct PlainChannel {
+    terminal: Terminal,
+    unit: Unit,
+    name: String,
+    series: Vec<usize>,
+    lengths: Vec<usize>,
+}
+
+fn checksum(bytes: &[u8]) -> u32 {
+    let mut hasher = crc32fast::Hasher::new();
+    hasher.update(bytes);
+    hasher.finalize()
+}
+
+fn invalid(reason: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, reason)
+}
+
+fn encode_column(vector: &[f64], encoding: Encoding) -> (Column, Vec<u8>) {
+    let width = match encoding.precision {
+        Precision::F64 => 8,
+        Precision::F32 => 4,
+    };
+    let bits: Vec<u64> = vector
+        .iter()
+        .map(|value| match encoding.precision {
+            Precision::F64 => value.to_bits(),
+            Precision::F32 => (*value as f32).to_bits() as u64,
+        })
+        .collect();
+    let bits: Vec<u64> = if encoding.delta {
+        let mut previous = 0u64;
+        bits.into_iter()
+            .map(|bits| {
+                let delta = bits.wrapping_sub(previous);
+                previous = bits;
+                delta
+            })
+            .collect()
+    } else {
+        bits
+    };
+    let mut bytes: Vec<u8> = Vec::with_capacity(bits.len() * width);
+    for value in bits.iter() {
+        bytes.extend_from_slice(&value.to_le_bytes()[..width]);
+    }
+    if encoding.compress {
+        //the same byte of every sample next to each other, exponents and high mantissa bytes
+        //repeat far more often than the low ones
+        let shuffled: Vec<u8> = (0..width)
+            .flat_map(|plane| bytes.iter().skip(plane).step_by(width).copied())
+            .collect();
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&shuffled).unwrap();
+        bytes = encoder.finish().unwrap();
+    }
+    let column = Column {
+        samples: vector.len(),
+        precision: encoding.precision,
+        delta: encoding.delta,
+        compressed: encoding.compress,
+        bytes: bytes.len(),
+    };
+    (column, bytes)
+}
+
+fn decode_column(column: &Column, bytes: &[u8]) -> io::Result<Vec<f64>> {
+    let width = match column.precision {
+        Precision::F64 => 8,
+        Precision::F32 => 4,
+    };
+    let bytes: Vec<u8> = if column.compressed {
+        let mut shuffled = Vec::with_capacity(column.samples * width);
+        DeflateDecoder::new(bytes).read_to_end(&mut shuffled)?;
+        if shuffled.len() != column.samples * width {
+            return Err(invalid("wrong number of samples"));
+        }
+        let mut bytes = vec![0u8; shuffled.len()];
+        for (plane, bytes_of_plane) in shuffled.chunks_exact(column.samples.max(1)).enumerate() {
+            for (sample, byte) in bytes_of_plane.iter().enumerate() {
+                bytes[sample * width + plane] = *byte;
+            }
+        }
+        bytes
+    } else {
+        bytes.to_vec()
+    };
+    if bytes.len() != column.samples * width {
+        return Err(invalid("wrong number of samples"));
+    }
+    let mut previous = 0u64;
+    Ok(bytes
+        .chunks_exact(width)
+        .map(|chunk| {
+            let mut word = [0u8; 8];
+            word[..width].copy_from_slice(chunk);
+            let mut bits = u64::from_le_bytes(word);
+            if column.delta {
+                bits = bits.wrapping_add(previous);
+                previous = bits;
+            }
+            match column.precision {
+                Precision::F64 => f64::from_bits(bits),
+                Precision::F32 => f32::from_bits(bits as u32) as f64,
+            }
+        })
+        .collect())
+}
+
+//"KDAT", version, header length (u32), json header, columns, crc32 of everything before it
+pub fn encode(test_data: &[TestData], encoding: Encoding) -> Vec<u8> {
+    let mut channels = vec![];
+    let mut payload = vec![];
+    for t in test_data {
+        let mut columns = vec![];
+        for vector in t.data.iter() {
+            let (column, bytes) = encode_column(vector, encoding);
+            columns.push(column);
+            payload.extend_from_slice(&bytes);
+        }
+        channels.push(Channel {
+            terminal: t.terminal,
+            unit: t.unit,
+            name: t.name.clone(),
+            series: t.series.clone(),
+            columns,
+        });
+    }
+    let header = serde_json::to_vec(&channels).unwrap();
+    let mut bytes = Vec::with_capacity(13 + header.len() + payload.len());
+    bytes.extend_from_slice(MAGIC);
+    bytes.push(VERSION);
+    bytes.extend_from_slice(&(header.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(&header);
+    bytes.extend_from_slice(&payload);
+    let crc = checksum(&bytes);
+    bytes.extend_from_slice(&crc.to_le_bytes());
+    bytes
+}
+
+pub fn decode(bytes: &[u8]) -> io::Result<Vec<TestData>> {
+    if bytes.len() < 9 || &bytes[..4] != MAGIC {
+        return Err(invalid("not a sample blob"));
+    }
+    let header_length = u32::from_le_bytes(bytes[5..9].try_into().unwrap()) as usize;
+    let header = bytes
+        .get(9..9 + header_length)
+        .ok_or_else(|| invalid("truncated header"))?;
+    match bytes[4] {
+        1 => decode_plain(header, &bytes[9 + header_length..]),
+        2 => {
+            let (content, stored) = bytes.split_at(bytes.len() - 4);
+            if content.len() < 9 + header_length {
+                return Err(invalid("truncated blob"));
+            }
+            if checksum(content) != u32::from_le_bytes(stored.try_into().unwrap()) {
+                return Err(invalid("checksum mismatch"));
+            }
+            let channels: Vec<Channel> = serde_json::from_slice(header)?;
+            let mut rest = &content[9 + header_length..];
+            let mut test_data = vec![];
+            for channel in channels {
+                let mut data = vec![];
+                for column in channel.columns.iter() {
+                    if rest.len() < column.bytes {
+                        return Err(invalid("truncated samples"));
+                    }
+                    let (bytes, next) = rest.split_at(column.bytes);
+                    data.push(decode_column(column, bytes)?);
+                    rest = next;
+                }
+                test_data.push(TestData {
+                    terminal: channel.terminal,
+                    unit: channel.unit,
+                    name: channel.name,
+                    series: channel.series,
+                    data,
+                });
+            }
+            Ok(test_data)
+        }
+        version => Err(invalid(&format!("unknown blob version {}", version))),
+    }
+}
+
+fn decode_plain(header: &[u8], samples: &[u8]) -> io::Result<Vec<TestData>> {
+    let channels: Vec<PlainChannel> = serde_json::from_slice(header)?;
+    let mut samples = samples
+        .chunks_exact(8)
+        .map(|chunk| f64::from_le_bytes(chunk.try_into().unwrap()));
+    let mut test_data = vec![];
+    for channel in channels {
+        let data = channel
+            .lengths
+            .iter()
+            .map(|length| {
+                let vector: Vec<f64> = samples.by_ref().take(*length).collect();
+                if vector.len() == *length {
+                    Ok(vector)
+                } else {
+                    Err(invalid("truncated samples"))
+                }
+            })
+            .collect::<io::Result<Vec<Vec<f64>>>>()?;
+        test_data.push(TestData {
+            terminal: channel.terminal,
+            unit: channel.unit,
+            name: channel.name,
+            series: channel.series,
+            data,
+        });
+    }
+    Ok(test_data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //a sweep with two series and a formula channel without samples
+    fn samples() -> Vec<TestData> {
+        let gate: Vec<f64> = (0..101).map(|n| -1.0 + 0.02 * n as f64).collect();
+        let current = |series: f64| -> Vec<f64> {
+            gate.iter()
+                .map(|v| series * 1e-9 * (v * 3.0).exp() - 1e-13)
+                .collect()
+        };
+        vec![
+            TestData {
+                terminal: Terminal::Gate,
+                unit: Unit::Voltage,
+                name: String::from("GateV"),
+                series: vec![1, 2],
+                data: vec![gate.clone(), gate.clone()],
+            },
+            TestData {
+                terminal: Terminal::Drain,
+                unit: Unit::Current,
+                name: String::from("DrainI"),
+                series: vec![1, 2],
+                data: vec![current(1.0), current(2.5)],
+            },
+            TestData {
+                terminal: Terminal::Other,
+                unit: Unit::Other,
+                name: String::from("GM"),
+                series: vec![],
+                data: vec![vec![]],
+            },
+        ]
+    }
+
+    fn assert_same(decoded: &[TestData], expected: &[TestData], round: fn(f64) -> f64) {
+        assert_eq!(decoded.len(), expected.len());
+        for (decoded, expected) in decoded.iter().zip(expected.iter()) {
+            assert_eq!(decoded.terminal, expected.terminal);
+            assert_eq!(decoded.unit, expected.unit);
+            assert_eq!(decoded.name, expected.name);
+            assert_eq!(decoded.series, expected.series);
+            let rounded: Vec<Vec<u64>> = expected
+                .data
+                .iter()
+                .map(|vector| vector.iter().map(|v| round(*v).to_bits()).collect())
+                .collect();
+            let bits: Vec<Vec<u64>> = decoded
+                .data
+                .iter()
+                .map(|vector| vector.iter().map(|v| v.to_bits()).collect())
+                .collect();
+            assert_eq!(bits, rounded, "{}", expected.name);
+        }
+    }
+
+    #[test]
+    fn every_encoding_round_trips() {
+        let test_data = samples();
+        for precision in [Precision::F64, Precision::F32].iter() {
+            for delta in [false, true].iter() {
+                for compress in [false, true].iter() {
+                    let encoding = Encoding {
+                        precision: *precision,
+                        delta: *delta,
+                        compress: *compress,
+                    };
+                    let decoded = decode(&encode(&test_data, encoding)).unwrap();
+                    let round = match precision {
+                        Precision::F64 => |v| v,
+                        Precision::F32 => |v| v as f32 as f64,
+                    };
+                    assert_same(&decoded, &test_data, round);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn reads_version_1() {
+        let test_data = samples();
+        let header = serde_json::to_vec(&serde_json::json!(test_data
+            .iter()
+            .map(|t| serde_json::json!({
+                "terminal": t.terminal,
+                "unit": t.unit,
+                "name": t.name,
+                "series": t.series,
+                "lengths": t.data.iter().map(|vector| vector.len()).collect::<Vec<_>>()
+            }))
+            .collect::<Vec<_>>()))
+        .unwrap();
+        let mut bytes = MAGIC.to_vec();
+        bytes.push(1);
+        bytes.extend_from_slice(&(header.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&header);
+        for value in test_data.iter().flat_map(|t| t.data.iter().flatten()) {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        assert_same(&decode(&bytes).unwrap(), &test_data, |v| v);
+        bytes.truncate(bytes.len() - 8);
+        assert!(decode(&bytes).is_err());
+    }
+
+    #[test]
+    fn damaged_blobs_are_rejected() {
+        let mut bytes = encode(&samples(), Encoding::default());
+        let middle = bytes.len() / 2;
+        bytes[middle] ^= 1;
+        assert_eq!(decode(&bytes).unwrap_err().to_string(), "checksum mismatch");
+        bytes[4] = 3;
+        assert!(decode(&bytes).is_err());
+    }
+}
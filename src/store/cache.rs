@@ -0,0 +1,75 @@
+use crate::measurement::testdata::TestData;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+//samples of the runs used last, bounded by the size of their samples in bytes
+pub struct Cache {
+    capacity: usize,
+    size: usize,
+    entries: HashMap<String, Arc<Vec<TestData>>>,
+    //least recently used first
+    order: VecDeque<String>,
+}
+
+fn size_of(test_data: &[TestData]) -> usize {
+    test_data
+        .iter()
+        .flat_map(|t| t.data.iter())
+        .map(|vector| vector.len() * std::mem::size_of::<f64>())
+        .sum()
+}
+
+impl Cache {
+    pub fn new(capacity: usize) -> Cache {
+        Cache {
+            capacity,
+            size: 0,
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    pub fn get(&mut self, id: &str) -> Option<Arc<Vec<TestData>>> {
+        let test_data = self.entries.get(id)?.clone();
+        if let Some(position) = self.order.iter().position(|used| used == id) {
+            let id = self.order.remove(position).unwrap();
+            self.order.push_back(id);
+        }
+        Some(test_data)
+    }
+
+    //a run larger than the whole cache is not kept
+    pub fn insert(&mut self, id: &str, test_data: Arc<Vec<TestData>>) {
+        self.remove(id);
+        let size = size_of(&test_data);
+        if size > self.capacity {
+            return;
+        }
+        while self.size + size > self.capacity {
+            match self.order.pop_front() {
+                Some(oldest) => {
+                    if let Some(evicted) = self.entries.remove(&oldest) {
+                        self.size -= size_of(&evicted);
+                    }
+                }
+                None => break,
+            }
+        }
+        self.size += size;
+        self.entries.insert(id.to_string(), test_data);
+        self.order.push_back(id.to_string());
+    }
+
+    pub fn remove(&mut self, id: &str) {
+        if let Some(removed) = self.entries.remove(id) {
+            self.size -= size_of(&removed);
+            self.order.retain(|used| used != id);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+        self.size = 0;
+    }
+}
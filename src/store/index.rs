@@ -0,0 +1,155 @@
+use crate::database::Database;
+use crate::elm::facets::FacetIndex;
+use crate::elm::filter::{FilterOptions, FilterQuery};
+use crate::measurement::MeasurementCompact;
+use crate::query::Query;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub type IndexWrite<'a> = Box<dyn FnOnce() -> io::Result<()> + 'a>;
+
+//where the index of the library is kept, the samples are in the blobs of the store either way
+pub trait Index: Send + Sync {
+    //file of the index, for messages
+    fn path(&self) -> PathBuf;
+
+    //the library and the schema version it had, None when there is no library yet
+    fn load(&self) -> Result<Option<(Database, u64)>, String>;
+
+    //the write of the index as it is now, taken while the library is locked and run once it is
+    //unlocked, `removed` are the ids of runs that are no longer in the library
+    fn prepare(&self, database: &Database, removed: &[String]) -> io::Result<IndexWrite<'_>>;
+
+    fn filter(&self, database: &Database, query: &FilterQuery) -> Vec<MeasurementCompact> {
+        query.filter(database.measurements.clone())
+    }
+
+    //runs matching a text query, evaluated on the runs of the library in memory with either backend,
+    //only the matching runs are copied
+    fn query(&self, database: &Database, query: &Query) -> Vec<MeasurementCompact> {
+        query
+            .select(&database.measurements)
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    //facet counts of the whole library, or of the runs matching the query with each facet relaxed
+    fn options(&self, database: &Database, query: Option<&FilterQuery>) -> FilterOptions {
+        match query {
+            Some(query) => FilterOptions::filtered(&database.measurements, query.clone()),
+            None => FilterOptions::new(&database.measurements),
+        }
+    }
+}
+
+//copy of an index file, taken before it is migrated or when it cannot be read
+pub fn backup(path: &Path) -> io::Result<PathBuf> {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs());
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".{}.bak", seconds));
+    let backup = path.with_file_name(name);
+    fs::copy(path, &backup)?;
+    Ok(backup)
+}
+
+//result.json, the whole library is read and written at once and filtered in memory
+pub struct JsonIndex {
+    path: PathBuf,
+    //facets of the runs of the last library counted, by its revision
+    facets: Mutex<Option<(u64, Arc<FacetIndex>)>>,
+}
+
+impl JsonIndex {
+    pub fn new(dir: &Path) -> JsonIndex {
+        JsonIndex {
+            path: dir.join("result.json"),
+            facets: Mutex::new(None),
+        }
+    }
+
+    //built again only when the runs changed since the last count
+    fn facets(&self, database: &Database) -> Arc<FacetIndex> {
+        let mut facets = self.facets.lock().unwrap();
+        match facets.as_ref() {
+            Some((revision, index)) if *revision == database.revision => Arc::clone(index),
+            _ => {
+                let index = Arc::new(FacetIndex::new(&database.measurements));
+                *facets = Some((database.revision, Arc::clone(&index)));
+                index
+            }
+        }
+    }
+}
+
+impl Index for JsonIndex {
+    fn path(&self) -> PathBuf {
+        self.path.clone()
+    }
+
+    fn load(&self) -> Result<Option<(Database, u64)>, String> {
+        match fs::read_to_string(&self.path) {
+            Ok(string) => Database::load(string.as_str()).map(Some),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    //written next to result.json and renamed, so an interrupted save leaves the previous index
+    fn prepare(&self, database: &Database, _: &[String]) -> io::Result<IndexWrite<'_>> {
+        let json = serde_json::to_string(database)?;
+        Ok(Box::new(move || {
+            let written = self.path.with_extension("json.tmp");
+            fs::write(&written, json)?;
+            fs::rename(&written, &self.path)
+        }))
+    }
+
+    //the runs are taken from the bitsets of the facets instead of a pass over all runs
+    fn filter(&self, database: &Database, query: &FilterQuery) -> Vec<MeasurementCompact> {
+        self.facets(database)
+            .select(&database.measurements, query)
+            .iter()
+            .map(|run| database.measurements[run].clone())
+            .collect()
+    }
+
+    fn options(&self, database: &Database, query: Option<&FilterQuery>) -> FilterOptions {
+        let index = self.facets(database);
+        match query {
+            Some(query) => index.options(&database.measurements, query),
+            None => index.all(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    //a save that cannot be written leaves the index of the last save
+    #[test]
+    fn a_failed_save_keeps_the_previous_index() {
+        let dir = env::temp_dir().join(format!("data_library-index-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let index = JsonIndex::new(&dir);
+        let mut database = Database::new();
+        database.measurements = (0..3).map(crate::elm::facets::tests::run).collect();
+        index.prepare(&database, &[]).unwrap()().unwrap();
+        assert!(!dir.join("result.json.tmp").exists());
+
+        fs::create_dir(dir.join("result.json.tmp")).unwrap();
+        database.measurements.clear();
+        assert!(index.prepare(&database, &[]).unwrap()().is_err());
+        let (loaded, _) = index.load().unwrap().unwrap();
+        assert_eq!(loaded.measurements.len(), 3);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
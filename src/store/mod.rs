@@ -0,0 +1,293 @@
+use crate::database::Database;
+use crate::elm::filter::{FilterOptions, FilterQuery};
+use crate::measurement::testdata::TestData;
+use crate::measurement::{Measurement, MeasurementCompact};
+use crate::query::Query;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+pub mod archive;
+
+pub mod blob;
+
+pub mod cache;
+
+pub mod index;
+
+pub mod sqlite;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StoreConfig {
+    //upper bound for the samples kept in memory
+    #[serde(default = "default_cache_megabytes")]
+    pub cache_megabytes: usize,
+    //how the samples of new runs are written
+    #[serde(default)]
+    pub encoding: blob::Encoding,
+    //where the index is kept
+    #[serde(default = "default_backend")]
+    pub backend: Backend,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Backend {
+    //result.json
+    Json,
+    //library.sqlite, imports result.json when it is empty
+    Sqlite,
+}
+
+impl Default for StoreConfig {
+    fn default() -> Self {
+        StoreConfig {
+            cache_megabytes: default_cache_megabytes(),
+            encoding: Default::default(),
+            backend: default_backend(),
+        }
+    }
+}
+
+fn default_cache_megabytes() -> usize {
+    256
+}
+
+fn default_backend() -> Backend {
+    Backend::Json
+}
+
+//the index of the library in result.json or library.sqlite and the samples of every run in
+//data/<id>.bin, samples are read when a run is processed and kept in a cache of bounded size
+pub struct Store {
+    dir: PathBuf,
+    encoding: blob::Encoding,
+    cache: Mutex<cache::Cache>,
+    //samples of new runs until their blob is written, they are read from here meanwhile
+    pending: Mutex<HashMap<String, Arc<Vec<TestData>>>>,
+    //held from preparing a save until it is written, so saves reach the disk in order
+    saving: Mutex<()>,
+    index: Box<dyn index::Index>,
+}
+
+//a save taken while the library is locked and written after it is unlocked
+pub struct Save<'a> {
+    store: &'a Store,
+    _saving: MutexGuard<'a, ()>,
+    removed: Vec<String>,
+    changed: BTreeSet<String>,
+    index: index::IndexWrite<'a>,
+}
+
+impl<'a> Save<'a> {
+    //writes the samples of new runs, deletes those of removed runs and writes the index,
+    //returns the ids of the runs written to the index
+    pub fn write(self) -> io::Result<BTreeSet<String>> {
+        let store = self.store;
+        fs::create_dir_all(store.dir.join("data"))?;
+        let ids: Vec<String> = store.pending.lock().unwrap().keys().cloned().collect();
+        for id in ids {
+            let test_data = match store.pending.lock().unwrap().get(&id) {
+                Some(test_data) => Arc::clone(test_data),
+                None => continue,
+            };
+            store.write_blob(&id, &test_data)?;
+            store.pending.lock().unwrap().remove(&id);
+            store.cache.lock().unwrap().remove(&id);
+        }
+        for id in self.removed.iter() {
+            store.cache.lock().unwrap().remove(id);
+            match fs::remove_file(store.blob_path(id)) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+                _ => {}
+            }
+        }
+        (self.index)()?;
+        Ok(self.changed)
+    }
+}
+
+impl Store {
+    pub fn new(output_dir: &str, config: &StoreConfig) -> Result<Store, String> {
+        let dir = PathBuf::from(output_dir);
+        let index: Box<dyn index::Index> = match config.backend {
+            Backend::Json => Box::new(index::JsonIndex::new(&dir)),
+            Backend::Sqlite => Box::new(sqlite::SqliteIndex::open(&dir)?),
+        };
+        Ok(Store {
+            dir,
+            encoding: config.encoding,
+            cache: Mutex::new(cache::Cache::new(config.cache_megabytes * 1024 * 1024)),
+            pending: Mutex::new(HashMap::new()),
+            saving: Mutex::new(()),
+            index,
+        })
+    }
+
+    //a library written by another instance, its index is read with the backend it was written with
+    pub fn existing(dir: &Path, config: &StoreConfig) -> Result<Store, String> {
+        let backend = if dir.join("library.sqlite").exists() {
+            Backend::Sqlite
+        } else {
+            Backend::Json
+        };
+        Store::new(
+            dir.to_string_lossy().as_ref(),
+            &StoreConfig {
+                backend,
+                ..config.clone()
+            },
+        )
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn index_path(&self) -> PathBuf {
+        self.index.path()
+    }
+
+    //the library and the schema version it had, None when there is no library yet
+    pub fn load_index(&self) -> Result<Option<(Database, u64)>, String> {
+        self.index.load()
+    }
+
+    //copy of the index, taken before it is migrated or when it cannot be read
+    pub fn backup(&self) -> io::Result<PathBuf> {
+        index::backup(&self.index.path())
+    }
+
+    pub fn filter(&self, database: &Database, query: &FilterQuery) -> Vec<MeasurementCompact> {
+        self.index.filter(database, query)
+    }
+
+    pub fn query(&self, database: &Database, query: &Query) -> Vec<MeasurementCompact> {
+        self.index.query(database, query)
+    }
+
+    pub fn options(&self, database: &Database, query: Option<&FilterQuery>) -> FilterOptions {
+        self.index.options(database, query)
+    }
+
+    fn blob_path(&self, id: &str) -> PathBuf {
+        self.dir.join("data").join(format!("{}.bin", id))
+    }
+
+    //written next to the blob and renamed, so an interrupted write never leaves half a blob
+    fn write_blob(&self, id: &str, test_data: &[TestData]) -> io::Result<()> {
+        let path = self.blob_path(id);
+        let written = path.with_extension("bin.tmp");
+        fs::write(&written, blob::encode(test_data, self.encoding))?;
+        fs::rename(&written, &path)
+    }
+
+    //the samples of a run, from the cache or read from disk
+    pub fn test_data(&self, measurement: &MeasurementCompact) -> io::Result<Arc<Vec<TestData>>> {
+        let id = measurement.id.as_str();
+        if let Some(test_data) = self.pending.lock().unwrap().get(id) {
+            return Ok(Arc::clone(test_data));
+        }
+        if let Some(test_data) = self.cache.lock().unwrap().get(id) {
+            return Ok(test_data);
+        }
+        let test_data = Arc::new(self.read(measurement)?);
+        self.cache
+            .lock()
+            .unwrap()
+            .insert(id, Arc::clone(&test_data));
+        Ok(test_data)
+    }
+
+    fn read(&self, measurement: &MeasurementCompact) -> io::Result<Vec<TestData>> {
+        let id = measurement.id.as_str();
+        match fs::read(self.blob_path(id)) {
+            Ok(bytes) => blob::decode(&bytes),
+            //runs without samples may have no blob
+            Err(e) if e.kind() == io::ErrorKind::NotFound && measurement.test_data.is_empty() => {
+                Ok(vec![])
+            }
+            Err(e) => Err(e),
+        }
+        .map_err(|e| io::Error::new(e.kind(), format!("samples of {}: {}", id, e)))
+    }
+
+    //the encoded samples of a run, None for runs without samples
+    pub fn blob(&self, id: &str) -> io::Result<Option<Vec<u8>>> {
+        match fs::read(self.blob_path(id)) {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn load(&self, measurement: &MeasurementCompact) -> io::Result<Measurement> {
+        let test_data = self.test_data(measurement)?;
+        Ok(measurement.clone().with_data(test_data.as_ref().clone()))
+    }
+
+    //writes the samples of new runs, deletes those of removed runs and writes the index
+    pub fn save(&self, database: &mut Database) -> io::Result<()> {
+        let written = self.prepare(database)?.write()?;
+        database.changed.retain(|id| !written.contains(id));
+        Ok(())
+    }
+
+    //takes what a save writes, the samples of new runs are kept by the store until written
+    //the library can be unlocked before the save is written, the runs it wrote to the index are
+    //taken out of `changed` afterwards
+    pub fn prepare(&self, database: &mut Database) -> io::Result<Save<'_>> {
+        let saving = self.saving.lock().unwrap();
+        self.pending.lock().unwrap().extend(
+            database
+                .unsaved
+                .drain()
+                .map(|(id, test_data)| (id, Arc::new(test_data))),
+        );
+        //an updated run keeps its id and blob
+        let removed: Vec<String> = std::mem::take(&mut database.discarded)
+            .into_iter()
+            .filter(|id| !database.measurements.iter().any(|m| &m.id == id))
+            .collect();
+        Ok(Save {
+            store: self,
+            _saving: saving,
+            index: self.index.prepare(database, &removed)?,
+            removed,
+            changed: database.changed.clone(),
+        })
+    }
+
+    //rewrites the samples of every run with the configured encoding, one run at a time,
+    //returns the size of the library before and after in bytes
+    pub fn convert(&self, database: &mut Database) -> io::Result<(u64, u64)> {
+        let before = self.size()?;
+        fs::create_dir_all(self.dir.join("data"))?;
+        for measurement in database.measurements.iter() {
+            let test_data = match database.unsaved.remove(&measurement.id) {
+                Some(test_data) => test_data,
+                None => self.read(measurement)?,
+            };
+            self.write_blob(&measurement.id, &test_data)?;
+        }
+        self.cache.lock().unwrap().clear();
+        self.save(database)?;
+        Ok((before, self.size()?))
+    }
+
+    //bytes of the index and all blobs
+    fn size(&self) -> io::Result<u64> {
+        let index = fs::metadata(self.index_path()).map_or(0, |metadata| metadata.len());
+        let blobs = match fs::read_dir(self.dir.join("data")) {
+            Ok(entries) => entries
+                .map(|entry| Ok(entry?.metadata()?.len()))
+                .sum::<io::Result<u64>>()?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => 0,
+            Err(e) => return Err(e),
+        };
+        Ok(index + blobs)
+    }
+}
@@ -0,0 +1,935 @@
+use super::index::{Index, IndexWrite, JsonIndex};
+use crate::database::migration::SCHEMA_VERSION;
+use crate::database::{Database, ScannedFile};
+use crate::elm::filter::{exact_keys, same_setting, FilterOptions, FilterQuery};
+use crate::elm::range::{self, exact_key, Quantity, QUANTITIES};
+use crate::elm::terminal::{values_of, Property, TerminalOptions};
+use crate::ingest::fingerprint::Fingerprint;
+use crate::measurement::settings::Settings;
+use crate::measurement::terminal_parameter::terminal::Terminal;
+use crate::measurement::MeasurementCompact;
+use rusqlite::functions::FunctionFlags;
+use rusqlite::types::Value;
+use rusqlite::{
+    params, Connection, OptionalExtension, Transaction, TransactionBehavior, NO_PARAMS,
+};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS meta (
+    key TEXT PRIMARY KEY,
+    value TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS files (
+    path TEXT PRIMARY KEY,
+    size INTEGER,
+    modified INTEGER,
+    hash TEXT,
+    vanished INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS measurements (
+    id TEXT PRIMARY KEY,
+    alias TEXT NOT NULL,
+    file_path TEXT NOT NULL,
+    sheet_name TEXT NOT NULL,
+    process TEXT,
+    die TEXT,
+    temperature INTEGER,
+    width REAL,
+    length REAL,
+    test_type TEXT NOT NULL,
+    measurement_speed TEXT NOT NULL,
+    date INTEGER NOT NULL,
+    entry TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS measurements_file ON measurements (file_path);
+CREATE TABLE IF NOT EXISTS terminal_parameters (
+    measurement_id TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    terminal TEXT NOT NULL,
+    instrument TEXT NOT NULL,
+    operational_mode TEXT NOT NULL,
+    bias REAL,
+    compliance REAL
+);
+CREATE INDEX IF NOT EXISTS terminal_parameters_measurement ON terminal_parameters (measurement_id);
+CREATE TABLE IF NOT EXISTS terminal_values (
+    measurement_id TEXT NOT NULL,
+    terminal TEXT NOT NULL,
+    property TEXT NOT NULL,
+    value TEXT NOT NULL,
+    number REAL
+);
+CREATE INDEX IF NOT EXISTS terminal_values_property ON terminal_values (terminal, property, measurement_id);
+CREATE TABLE IF NOT EXISTS channels (
+    measurement_id TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    terminal TEXT NOT NULL,
+    unit TEXT NOT NULL,
+    name TEXT NOT NULL,
+    count INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS channels_measurement ON channels (measurement_id);
+CREATE TABLE IF NOT EXISTS settings (
+    measurement_id TEXT NOT NULL,
+    terminal TEXT,
+    label TEXT NOT NULL,
+    value TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS settings_label ON settings (label, measurement_id);
+CREATE TABLE IF NOT EXISTS annotations (
+    measurement_id TEXT NOT NULL,
+    key TEXT NOT NULL,
+    value TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS annotations_measurement ON annotations (measurement_id);
+";
+
+//tables that hold rows per run
+const PER_RUN: [&str; 5] = [
+    "terminal_parameters",
+    "terminal_values",
+    "channels",
+    "settings",
+    "annotations",
+];
+
+//the facets of FilterQuery, a facet is left out of the conditions when its own counts are computed
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Facet {
+    SheetNames,
+    Widths,
+    Lengths,
+    Temps,
+    Processes,
+    Dies,
+    TestTypes,
+    Speeds,
+    Dates,
+    //the ranges of a quantity, for the counts of its bins
+    Range(Quantity),
+    //the filters of a property of a terminal
+    Terminal(Terminal, Property),
+}
+
+//the value of a quantity in a row of measurements, NULL when the run does not have it
+fn expression(quantity: Quantity) -> &'static str {
+    match quantity {
+        Quantity::Width => "width",
+        Quantity::Length => "length",
+        Quantity::Ratio => "CASE WHEN length > 0 THEN width / length END",
+        Quantity::Area => "width * length",
+        Quantity::Temperature => "temperature",
+    }
+}
+
+//library.sqlite, only the runs that changed are written, filters and facet counts are queries
+//other instances can read the file, but a save replaces the meta and files of the library, so only
+//one of them should write it
+pub struct SqliteIndex {
+    dir: PathBuf,
+    connection: Mutex<Connection>,
+}
+
+fn to_io(e: rusqlite::Error) -> io::Error {
+    io::Error::other(e)
+}
+
+//enums are kept by their name
+fn text<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(string)) => string,
+        Ok(value) => value.to_string(),
+        Err(_) => String::new(),
+    }
+}
+
+//the enum of a name written by `text`
+fn parse<T: DeserializeOwned>(name: String) -> Option<T> {
+    serde_json::from_value(serde_json::Value::String(name)).ok()
+}
+
+fn date_of(measurement: &MeasurementCompact) -> i64 {
+    let time_stamp = measurement.test_time_stamp;
+    time_stamp.year as i64 * 10000 + time_stamp.month as i64 * 100 + time_stamp.day as i64
+}
+
+fn meta<T: DeserializeOwned + Default>(connection: &Connection, key: &str) -> Result<T, String> {
+    let value: Option<String> = connection
+        .query_row("SELECT value FROM meta WHERE key = ?", &[key], |row| {
+            row.get(0)
+        })
+        .optional()
+        .map_err(|e| e.to_string())?;
+    match value {
+        Some(value) => serde_json::from_str(value.as_str()).map_err(|e| format!("{}: {}", key, e)),
+        None => Ok(T::default()),
+    }
+}
+
+//`column IN (...)` for the given values
+fn one_of(column: &str, values: Vec<Value>, conditions: &mut Vec<String>, params: &mut Vec<Value>) {
+    let placeholders = vec!["?"; values.len()].join(", ");
+    conditions.push(format!("{} IN ({})", column, placeholders));
+    params.extend(values);
+}
+
+//the WHERE clause of a query, the same selection FilterQuery::filter makes in memory
+fn conditions(query: &FilterQuery, relaxed: Option<Facet>) -> (String, Vec<Value>) {
+    let mut conditions = vec![];
+    let mut params = vec![];
+    let texts = |values: &[String]| -> Vec<Value> {
+        values
+            .iter()
+            .map(|value| Value::Text(value.clone()))
+            .collect()
+    };
+    if relaxed != Some(Facet::SheetNames) {
+        one_of(
+            "sheet_name",
+            texts(&query.sheet_names),
+            &mut conditions,
+            &mut params,
+        );
+    }
+    //a run without width or length is compared as 0.001 but offered as 0, so it is only
+    //selected by the relaxed facet when some run has that width
+    for (facet, column, values) in [
+        (Facet::Widths, "width", &query.widths),
+        (Facet::Lengths, "length", &query.lengths),
+    ]
+    .iter()
+    {
+        let compared = format!("exact_key(COALESCE({}, 0.001))", column);
+        if relaxed == Some(*facet) {
+            conditions.push(format!(
+                "{} IN (SELECT exact_key(COALESCE({}, 0.0)) FROM measurements)",
+                compared, column
+            ));
+        } else {
+            one_of(
+                compared.as_str(),
+                texts(&exact_keys(values)),
+                &mut conditions,
+                &mut params,
+            );
+        }
+    }
+    if relaxed != Some(Facet::Temps) {
+        let temps = query
+            .temps
+            .iter()
+            .filter_map(|temp| {
+                let digits: String = temp.chars().filter(|c| c.is_ascii_digit()).collect();
+                digits.parse::<i64>().ok()
+            })
+            .map(Value::Integer)
+            .collect();
+        one_of(
+            "COALESCE(temperature, 0)",
+            temps,
+            &mut conditions,
+            &mut params,
+        );
+    }
+    if relaxed != Some(Facet::Processes) {
+        conditions.push("COALESCE(process, '') = ?".to_string());
+        params.push(Value::Text(query.wafer.clone()));
+    }
+    if relaxed != Some(Facet::Dies) {
+        one_of(
+            "COALESCE(die, '')",
+            texts(&query.dies),
+            &mut conditions,
+            &mut params,
+        );
+    }
+    if relaxed != Some(Facet::TestTypes) {
+        conditions.push("test_type = ?".to_string());
+        params.push(Value::Text(query.test_type.clone()));
+    }
+    if relaxed != Some(Facet::Speeds) {
+        one_of(
+            "measurement_speed",
+            texts(&query.measurement_speeds),
+            &mut conditions,
+            &mut params,
+        );
+    }
+    if relaxed != Some(Facet::Dates) {
+        let day = |date: Option<crate::measurement::timestamp::Date>, otherwise: i64| {
+            date.map_or(otherwise, |date| {
+                date.year as i64 * 10000 + date.month as i64 * 100 + date.day as i64
+            })
+        };
+        conditions.push("date BETWEEN ? AND ?".to_string());
+        params.push(Value::Integer(day(query.dates_between.0, 0)));
+        params.push(Value::Integer(day(query.dates_between.1, u32::MAX as i64)));
+    }
+    for setting in query.settings.iter() {
+        let section = match setting.terminal.as_ref() {
+            Some(terminal) => {
+                params.push(Value::Text(terminal.clone()));
+                "s.terminal = ? COLLATE NOCASE"
+            }
+            None => "s.terminal IS NULL",
+        };
+        conditions.push(format!(
+            "EXISTS (SELECT 1 FROM settings s WHERE s.measurement_id = measurements.id \
+             AND {} AND s.label = ? AND same_setting(s.value, ?))",
+            section
+        ));
+        params.push(Value::Text(setting.label.clone()));
+        params.push(Value::Text(setting.value.clone()));
+    }
+    for filter in query.terminals.iter() {
+        if relaxed == Some(Facet::Terminal(filter.terminal, filter.property)) {
+            continue;
+        }
+        let mut condition = "EXISTS (SELECT 1 FROM terminal_values t WHERE \
+                             t.measurement_id = measurements.id AND t.terminal = ? AND t.property = ?"
+            .to_string();
+        params.push(Value::Text(text(&filter.terminal)));
+        params.push(Value::Text(text(&filter.property)));
+        let keys = filter.keys();
+        if !keys.is_empty() {
+            condition.push_str(&format!(
+                " AND t.value COLLATE NOCASE IN ({})",
+                vec!["?"; keys.len()].join(", ")
+            ));
+            params.extend(keys.into_iter().map(Value::Text));
+        }
+        let (low, high) = filter.bounds();
+        if let Some(low) = low {
+            condition.push_str(" AND t.number >= ?");
+            params.push(Value::Real(low));
+        }
+        if let Some(high) = high {
+            condition.push_str(" AND t.number <= ?");
+            params.push(Value::Real(high));
+        }
+        condition.push(')');
+        conditions.push(condition);
+    }
+    for range in query.ranges.iter() {
+        if relaxed == Some(Facet::Range(range.quantity)) {
+            continue;
+        }
+        let value = expression(range.quantity);
+        conditions.push(format!("{} IS NOT NULL", value));
+        let (low, high) = range.bounds();
+        if let Some(low) = low {
+            conditions.push(format!("{} >= ?", value));
+            params.push(Value::Real(low));
+        }
+        if let Some(high) = high {
+            conditions.push(format!(
+                "{} {} ?",
+                value,
+                if range.below_max { "<" } else { "<=" }
+            ));
+            params.push(Value::Real(high));
+        }
+    }
+    (conditions.join(" AND "), params)
+}
+
+impl SqliteIndex {
+    pub fn open(dir: &Path) -> Result<SqliteIndex, String> {
+        fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        let connection =
+            SqliteIndex::connect(&dir.join("library.sqlite")).map_err(|e| e.to_string())?;
+        Ok(SqliteIndex {
+            dir: dir.to_path_buf(),
+            connection: Mutex::new(connection),
+        })
+    }
+
+    fn connect(path: &Path) -> rusqlite::Result<Connection> {
+        let connection = Connection::open(path)?;
+        //another instance writing the library is waited for instead of failing
+        connection.busy_timeout(Duration::from_secs(30))?;
+        connection.query_row("PRAGMA journal_mode = WAL", NO_PARAMS, |_| Ok(()))?;
+        connection.execute_batch(SCHEMA)?;
+        connection.create_scalar_function(
+            "same_setting",
+            2,
+            FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+            |context| {
+                let found: String = context.get(0)?;
+                let wanted: String = context.get(1)?;
+                Ok(same_setting(found.as_str(), wanted.as_str()))
+            },
+        )?;
+        connection.create_scalar_function(
+            "exact_key",
+            1,
+            FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+            |context| Ok(exact_key(context.get::<f64>(0)?)),
+        )?;
+        Ok(connection)
+    }
+
+    fn read(&self, connection: &Connection) -> Result<Option<(Database, u64)>, String> {
+        let version: Option<u64> = meta(connection, "schema_version")?;
+        let version = match version {
+            Some(version) => version,
+            None => return Ok(None),
+        };
+        if version > SCHEMA_VERSION {
+            return Err(format!(
+                "written by a newer version with schema {}, this one reads up to schema {}",
+                version, SCHEMA_VERSION
+            ));
+        }
+        let mut database = Database::new();
+        database.id_day_counter = meta(connection, "id_day_counter")?;
+        database.migrated_ids = meta(connection, "migrated_ids")?;
+        database.ingest_report = meta(connection, "ingest_report")?;
+        database.saved = meta(connection, "saved")?;
+        let sql_error = |e: rusqlite::Error| e.to_string();
+        let mut files = connection
+            .prepare("SELECT path, size, modified, hash, vanished FROM files ORDER BY rowid")
+            .map_err(sql_error)?;
+        database.files = files
+            .query_map(NO_PARAMS, |row| {
+                let hash: Option<String> = row.get(3)?;
+                let size: Option<i64> = row.get(1)?;
+                let modified: Option<i64> = row.get(2)?;
+                Ok(ScannedFile {
+                    path: row.get(0)?,
+                    fingerprint: hash.map(|hash| Fingerprint {
+                        size: size.unwrap_or(0) as u64,
+                        modified: modified.unwrap_or(0) as u64,
+                        hash,
+                    }),
+                    vanished: row.get(4)?,
+                })
+            })
+            .map_err(sql_error)?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .map_err(sql_error)?;
+        database.measurements = self.select(connection, "1", vec![])?;
+        Ok(Some((database, version)))
+    }
+
+    fn select(
+        &self,
+        connection: &Connection,
+        conditions: &str,
+        params: Vec<Value>,
+    ) -> Result<Vec<MeasurementCompact>, String> {
+        let mut statement = connection
+            .prepare(&format!(
+                "SELECT id, entry FROM measurements WHERE {} ORDER BY rowid",
+                conditions
+            ))
+            .map_err(|e| e.to_string())?;
+        let rows = statement
+            .query_map(params, |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })
+            .map_err(|e| e.to_string())?;
+        let mut measurements = vec![];
+        for row in rows {
+            let (id, entry) = row.map_err(|e| e.to_string())?;
+            measurements
+                .push(serde_json::from_str(entry.as_str()).map_err(|e| format!("{}: {}", id, e))?);
+        }
+        Ok(measurements)
+    }
+
+    //counts per value of `key`, facets with a single selected value keep the values without runs
+    fn facet(
+        &self,
+        connection: &Connection,
+        key: &str,
+        query: Option<&FilterQuery>,
+        facet: Facet,
+        label: fn(Value) -> String,
+    ) -> rusqlite::Result<HashMap<String, u32>> {
+        let (conditions, params) = match query {
+            Some(query) => conditions(query, Some(facet)),
+            None => ("1".to_string(), vec![]),
+        };
+        let keep_empty =
+            query.is_some() && (facet == Facet::Processes || facet == Facet::TestTypes);
+        let mut statement = connection.prepare(&format!(
+            "SELECT {} AS facet, SUM(CASE WHEN {} THEN 1 ELSE 0 END) AS runs \
+             FROM measurements GROUP BY facet{}",
+            key,
+            conditions,
+            if keep_empty { "" } else { " HAVING runs > 0" }
+        ))?;
+        let rows = statement.query_map(params, |row| {
+            Ok((label(row.get::<_, Value>(0)?), row.get::<_, i64>(1)? as u32))
+        })?;
+        rows.collect()
+    }
+
+    //the values of a quantity in the runs of the query without its own ranges, counted in bins
+    fn bins(
+        &self,
+        connection: &Connection,
+        query: Option<&FilterQuery>,
+        quantity: Quantity,
+    ) -> rusqlite::Result<Vec<range::Bin>> {
+        let (conditions, params) = match query {
+            Some(query) => conditions(query, Some(Facet::Range(quantity))),
+            None => ("1".to_string(), vec![]),
+        };
+        let value = expression(quantity);
+        let mut statement = connection.prepare(&format!(
+            "SELECT {} FROM measurements WHERE {} AND {} IS NOT NULL",
+            value, conditions, value
+        ))?;
+        let values = statement
+            .query_map(params, |row| row.get::<_, f64>(0))?
+            .collect::<rusqlite::Result<Vec<f64>>>()?;
+        Ok(range::bins(quantity, values))
+    }
+
+    //adds the runs per value of the terminals of the runs that match `conditions`, of one
+    //property of one terminal when given
+    fn terminal_counts(
+        &self,
+        connection: &Connection,
+        (conditions, mut params): (String, Vec<Value>),
+        only: Option<(Terminal, Property)>,
+        options: &mut TerminalOptions,
+    ) -> rusqlite::Result<()> {
+        let mut sql = format!(
+            "SELECT terminal, property, value, COUNT(DISTINCT measurement_id) FROM terminal_values \
+             WHERE measurement_id IN (SELECT id FROM measurements WHERE {})",
+            conditions
+        );
+        if let Some((terminal, property)) = only {
+            sql.push_str(" AND terminal = ? AND property = ?");
+            params.push(Value::Text(text(&terminal)));
+            params.push(Value::Text(text(&property)));
+            options.entry(terminal).or_default().insert(property, HashMap::new());
+        }
+        sql.push_str(" GROUP BY terminal, property, value");
+        let mut statement = connection.prepare(&sql)?;
+        let rows = statement.query_map(params, |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, i64>(3)? as u32,
+            ))
+        })?;
+        for row in rows {
+            let (terminal, property, value, runs) = row?;
+            if let (Some(terminal), Some(property)) = (parse(terminal), parse(property)) {
+                options
+                    .entry(terminal)
+                    .or_default()
+                    .entry(property)
+                    .or_default()
+                    .insert(value, runs);
+            }
+        }
+        Ok(())
+    }
+
+    //counts of a filtered property are those of the query without its own filters
+    fn terminal_options(
+        &self,
+        connection: &Connection,
+        query: Option<&FilterQuery>,
+    ) -> rusqlite::Result<TerminalOptions> {
+        let mut options = TerminalOptions::new();
+        let query = match query {
+            Some(query) => query,
+            None => {
+                self.terminal_counts(connection, ("1".to_string(), vec![]), None, &mut options)?;
+                return Ok(options);
+            }
+        };
+        self.terminal_counts(connection, conditions(query, None), None, &mut options)?;
+        for filter in query.terminals.iter() {
+            let pair = (filter.terminal, filter.property);
+            self.terminal_counts(
+                connection,
+                conditions(query, Some(Facet::Terminal(pair.0, pair.1))),
+                Some(pair),
+                &mut options,
+            )?;
+        }
+        Ok(options)
+    }
+
+    fn options_of(
+        &self,
+        connection: &Connection,
+        query: Option<&FilterQuery>,
+    ) -> rusqlite::Result<FilterOptions> {
+        fn plain(value: Value) -> String {
+            match value {
+                Value::Text(text) => text,
+                Value::Real(number) => number.to_string(),
+                Value::Integer(number) => number.to_string(),
+                _ => String::new(),
+            }
+        }
+        fn kelvin(value: Value) -> String {
+            format!("{}K", plain(value))
+        }
+        //as Date is displayed
+        fn day(value: Value) -> String {
+            match value {
+                Value::Integer(date) => {
+                    format!("{}{}{}", date / 10000, date / 100 % 100, date % 100)
+                }
+                other => plain(other),
+            }
+        }
+        Ok(FilterOptions {
+            sheet_names: self.facet(connection, "sheet_name", query, Facet::SheetNames, plain)?,
+            widths: self.facet(
+                connection,
+                "exact_key(COALESCE(width, 0.0))",
+                query,
+                Facet::Widths,
+                plain,
+            )?,
+            lengths: self.facet(
+                connection,
+                "exact_key(COALESCE(length, 0.0))",
+                query,
+                Facet::Lengths,
+                plain,
+            )?,
+            temps: self.facet(
+                connection,
+                "COALESCE(temperature, 0)",
+                query,
+                Facet::Temps,
+                kelvin,
+            )?,
+            processes: self.facet(
+                connection,
+                "COALESCE(process, '')",
+                query,
+                Facet::Processes,
+                plain,
+            )?,
+            dies: self.facet(connection, "COALESCE(die, '')", query, Facet::Dies, plain)?,
+            test_types: self.facet(connection, "test_type", query, Facet::TestTypes, plain)?,
+            measurement_speeds: self.facet(
+                connection,
+                "measurement_speed",
+                query,
+                Facet::Speeds,
+                plain,
+            )?,
+            dates: self.facet(connection, "date", query, Facet::Dates, day)?,
+            bins: QUANTITIES
+                .iter()
+                .map(|quantity| Ok((*quantity, self.bins(connection, query, *quantity)?)))
+                .collect::<rusqlite::Result<_>>()?,
+            terminals: self.terminal_options(connection, query)?,
+        })
+    }
+
+    //the meta and files of the library as this instance knows them replace those in the file
+    fn write_meta(transaction: &Transaction, database: &Database) -> rusqlite::Result<()> {
+        let values = [
+            ("schema_version", serde_json::to_string(&SCHEMA_VERSION)),
+            (
+                "id_day_counter",
+                serde_json::to_string(&database.id_day_counter),
+            ),
+            (
+                "migrated_ids",
+                serde_json::to_string(&database.migrated_ids),
+            ),
+            (
+                "ingest_report",
+                serde_json::to_string(&database.ingest_report),
+            ),
+            ("saved", serde_json::to_string(&database.saved)),
+        ];
+        for (key, value) in values.iter() {
+            let value = value
+                .as_ref()
+                .map_err(|e| rusqlite::Error::ToSqlConversionFailure(e.to_string().into()))?;
+            transaction.execute(
+                "INSERT OR REPLACE INTO meta (key, value) VALUES (?, ?)",
+                params![key, value],
+            )?;
+        }
+        transaction.execute("DELETE FROM files", NO_PARAMS)?;
+        for file in database.files.iter() {
+            let fingerprint = file.fingerprint.as_ref();
+            transaction.execute(
+                "INSERT INTO files (path, size, modified, hash, vanished) VALUES (?, ?, ?, ?, ?)",
+                params![
+                    file.path,
+                    fingerprint.map(|f| f.size as i64),
+                    fingerprint.map(|f| f.modified as i64),
+                    fingerprint.map(|f| f.hash.clone()),
+                    file.vanished
+                ],
+            )?;
+        }
+        Ok(())
+    }
+
+    fn remove(transaction: &Transaction, id: &str) -> rusqlite::Result<()> {
+        for table in PER_RUN.iter() {
+            transaction.execute(
+                &format!("DELETE FROM {} WHERE measurement_id = ?", table),
+                &[id],
+            )?;
+        }
+        transaction.execute("DELETE FROM measurements WHERE id = ?", &[id])?;
+        Ok(())
+    }
+
+    //a rewritten run is inserted again, so runs stay in the order they were last extracted
+    fn write(transaction: &Transaction, measurement: &MeasurementCompact) -> rusqlite::Result<()> {
+        let id = measurement.id.as_str();
+        SqliteIndex::remove(transaction, id)?;
+        let entry = serde_json::to_string(measurement)
+            .map_err(|e| rusqlite::Error::ToSqlConversionFailure(e.into()))?;
+        let device = &measurement.device;
+        transaction.execute(
+            "INSERT INTO measurements (id, alias, file_path, sheet_name, process, die, temperature, \
+             width, length, test_type, measurement_speed, date, entry) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            params![
+                id,
+                measurement.alias,
+                measurement.file_path,
+                measurement.sheet_name,
+                device.wafer.as_ref().map(|process| process.to_string()),
+                device.die,
+                device.temperature.map(|temperature| temperature as i64),
+                device.width,
+                device.length,
+                measurement.test_parameter.test_type.to_string(),
+                measurement.test_parameter.measurement_speed.to_string(),
+                date_of(measurement),
+                entry
+            ],
+        )?;
+        for (position, terminal) in measurement.terminals.iter().enumerate() {
+            transaction.execute(
+                "INSERT INTO terminal_parameters (measurement_id, position, terminal, instrument, \
+                 operational_mode, bias, compliance) VALUES (?, ?, ?, ?, ?, ?, ?)",
+                params![
+                    id,
+                    position as i64,
+                    text(&terminal.terminal),
+                    text(&terminal.instrument),
+                    text(&terminal.operational_mode.op_type),
+                    terminal.operational_mode.bias,
+                    terminal.compliance
+                ],
+            )?;
+        }
+        for parameter in measurement.terminals.iter() {
+            for (property, value, number) in values_of(parameter) {
+                transaction.execute(
+                    "INSERT INTO terminal_values (measurement_id, terminal, property, value, number) \
+                     VALUES (?, ?, ?, ?, ?)",
+                    params![
+                        id,
+                        text(&parameter.terminal),
+                        text(&property),
+                        value,
+                        number
+                    ],
+                )?;
+            }
+        }
+        for (position, channel) in measurement.test_data.iter().enumerate() {
+            transaction.execute(
+                "INSERT INTO channels (measurement_id, position, terminal, unit, name, count) \
+                 VALUES (?, ?, ?, ?, ?, ?)",
+                params![
+                    id,
+                    position as i64,
+                    text(&channel.terminal),
+                    text(&channel.unit),
+                    channel.name,
+                    channel.count as i64
+                ],
+            )?;
+        }
+        let settings = &measurement.settings;
+        let mut sections: Vec<(Option<&str>, &_)> = vec![(None, &settings.global)];
+        //a terminal column without a name cannot be selected by a filter
+        sections.extend(settings.terminals.iter().filter_map(|values| {
+            Settings::terminal_name(values).map(|terminal| (Some(terminal), values))
+        }));
+        for (terminal, values) in sections {
+            for (label, value) in values.iter() {
+                transaction.execute(
+                    "INSERT INTO settings (measurement_id, terminal, label, value) VALUES (?, ?, ?, ?)",
+                    params![id, terminal, label, value],
+                )?;
+            }
+        }
+        for (key, value) in device.extra.iter() {
+            transaction.execute(
+                "INSERT INTO annotations (measurement_id, key, value) VALUES (?, ?, ?)",
+                params![id, key, value],
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Index for SqliteIndex {
+    fn path(&self) -> PathBuf {
+        self.dir.join("library.sqlite")
+    }
+
+    //an empty file takes over the library of result.json, which is left as it is
+    fn load(&self) -> Result<Option<(Database, u64)>, String> {
+        let connection = self.connection.lock().unwrap();
+        if let Some((mut database, version)) = self.read(&connection)? {
+            //runs written before terminal_values existed are written again on the next save
+            let indexed = connection
+                .prepare("SELECT DISTINCT measurement_id FROM terminal_values")
+                .and_then(|mut statement| {
+                    statement
+                        .query_map(NO_PARAMS, |row| row.get::<_, String>(0))?
+                        .collect::<rusqlite::Result<HashSet<String>>>()
+                })
+                .map_err(|e| e.to_string())?;
+            database.changed = database
+                .measurements
+                .iter()
+                .filter(|m| !m.terminals.is_empty() && !indexed.contains(&m.id))
+                .map(|m| m.id.clone())
+                .collect();
+            return Ok(Some((database, version)));
+        }
+        drop(connection);
+        let json = JsonIndex::new(&self.dir);
+        match json.load()? {
+            Some((mut database, _)) => {
+                println!(
+                    "Importing {} runs of {} into {}",
+                    database.measurements.len(),
+                    json.path().display(),
+                    self.path().display()
+                );
+                database.changed = database.measurements.iter().map(|m| m.id.clone()).collect();
+                Ok(Some((database, SCHEMA_VERSION)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    //only the runs that changed are taken along with the rest of the library
+    fn prepare(&self, database: &Database, removed: &[String]) -> io::Result<IndexWrite<'_>> {
+        let changed = Database {
+            files: database.files.clone(),
+            id_day_counter: database.id_day_counter.clone(),
+            migrated_ids: database.migrated_ids.clone(),
+            measurements: database
+                .measurements
+                .iter()
+                .filter(|measurement| database.changed.contains(&measurement.id))
+                .cloned()
+                .collect(),
+            ingest_report: database.ingest_report.clone(),
+            saved: database.saved.clone(),
+            ..Database::new()
+        };
+        let removed = removed.to_vec();
+        Ok(Box::new(move || {
+            let mut connection = self.connection.lock().unwrap();
+            let transaction = connection
+                .transaction_with_behavior(TransactionBehavior::Immediate)
+                .map_err(to_io)?;
+            SqliteIndex::write_meta(&transaction, &changed).map_err(to_io)?;
+            for id in removed.iter() {
+                SqliteIndex::remove(&transaction, id).map_err(to_io)?;
+            }
+            for measurement in changed.measurements.iter() {
+                SqliteIndex::write(&transaction, measurement).map_err(to_io)?;
+            }
+            transaction.commit().map_err(to_io)
+        }))
+    }
+
+    fn filter(&self, database: &Database, query: &FilterQuery) -> Vec<MeasurementCompact> {
+        let (conditions, params) = conditions(query, None);
+        self.select(
+            &self.connection.lock().unwrap(),
+            conditions.as_str(),
+            params,
+        )
+        .unwrap_or_else(|e| {
+            eprintln!("Cannot query {}: {}", self.path().display(), e);
+            query.filter(database.measurements.clone())
+        })
+    }
+
+    fn options(&self, database: &Database, query: Option<&FilterQuery>) -> FilterOptions {
+        self.options_of(&self.connection.lock().unwrap(), query)
+            .unwrap_or_else(|e| {
+                eprintln!("Cannot query {}: {}", self.path().display(), e);
+                match query {
+                    Some(query) => FilterOptions::filtered(&database.measurements, query.clone()),
+                    None => FilterOptions::new(&database.measurements),
+                }
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elm::facets::tests::{check, queries, run};
+    use std::env;
+
+    //the runs selected by the SQL conditions are those FilterQuery::filter keeps, in the same order,
+    //and the counts of the queries agree with the runs it keeps
+    #[test]
+    fn queries_agree_with_filter() {
+        let dir = env::temp_dir().join(format!("data_library-sqlite-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let index = SqliteIndex::open(&dir).unwrap();
+        let mut database = Database::new();
+        database.measurements = (0..2000).map(run).collect();
+        database.changed = database.measurements.iter().map(|m| m.id.clone()).collect();
+        index.prepare(&database, &[]).unwrap()().unwrap();
+        let runs = &database.measurements;
+        let all = index.options(&database, None);
+        for query in queries(&all).iter() {
+            let selected: Vec<String> = index
+                .filter(&database, query)
+                .into_iter()
+                .map(|m| m.id)
+                .collect();
+            let expected: Vec<String> = query
+                .filter(runs.clone())
+                .into_iter()
+                .map(|m| m.id)
+                .collect();
+            assert!(
+                !expected.is_empty() && expected.len() < runs.len(),
+                "{:?}",
+                query
+            );
+            assert_eq!(selected, expected, "{:?}", query);
+            check(runs, &all, query, &index.options(&database, Some(query)));
+        }
+        drop(index);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}